@@ -1,3 +1,3 @@
 pub mod web_api;
 
-pub use web_api::PlexWebApi;
\ No newline at end of file
+pub use web_api::{ClientIdentity, PlexWebApi, PlexWebApiBuilder};
@@ -0,0 +1,173 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use reqwest::{Certificate, Client, Proxy};
+
+use super::error::Error;
+use super::{ClientIdentity, PlexWebApi};
+
+/// Configures a [`PlexWebApi`] instance.
+///
+/// Transport settings (user agent, proxies, tls options, connect timeout) are only applied
+/// when the builder creates the underlying `reqwest::Client`. They are ignored when a custom
+/// client is provided via [`PlexWebApiBuilder::client`].
+#[derive(Debug)]
+pub struct PlexWebApiBuilder {
+    base_url: String,
+    auth_token: String,
+    client: Option<Client>,
+    timeout: Option<Duration>,
+    identity: Option<ClientIdentity>,
+    connect_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    proxies: Vec<Proxy>,
+    root_certificates: Vec<Certificate>,
+    accept_invalid_certs: bool,
+    machine_identifier: Option<String>,
+}
+
+impl PlexWebApiBuilder {
+    pub fn new<S: Into<String>>(base_url: S, auth_token: S) -> Self {
+        PlexWebApiBuilder {
+            base_url: base_url.into(),
+            auth_token: auth_token.into(),
+            client: None,
+            timeout: None,
+            identity: None,
+            connect_timeout: None,
+            user_agent: None,
+            proxies: Vec::new(),
+            root_certificates: Vec::new(),
+            accept_invalid_certs: false,
+            machine_identifier: None,
+        }
+    }
+
+    /// Use an existing client instead of building a new one.
+    pub fn client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    /// Timeout applied to every request, from connecting until the response body is read.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Client identification sent with every request and media url.
+    pub fn identity(mut self, identity: ClientIdentity) -> Self {
+        self.identity = Some(identity);
+        self
+    }
+
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    pub fn user_agent<S: Into<String>>(mut self, user_agent: S) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Can be called multiple times to register several proxies.
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Trust an additional root certificate, e.g. a self signed certificate of the server.
+    ///
+    /// Only applies to http requests, not to the notification websocket.
+    pub fn add_root_certificate(mut self, certificate: Certificate) -> Self {
+        self.root_certificates.push(certificate);
+        self
+    }
+
+    /// Accept any tls certificate presented by the server.
+    ///
+    /// This is required when connecting to the `*.plex.direct` address of a server
+    /// by its ip instead of the hostname. Use with care.
+    pub fn accept_invalid_certs(mut self, accept_invalid_certs: bool) -> Self {
+        self.accept_invalid_certs = accept_invalid_certs;
+        self
+    }
+
+    /// Machine identifier of the server, otherwise it is fetched on the first playlist, collection or play queue change.
+    pub fn machine_identifier<S: Into<String>>(mut self, machine_identifier: S) -> Self {
+        self.machine_identifier = Some(machine_identifier.into());
+        self
+    }
+
+    pub fn build(self) -> Result<PlexWebApi, Error> {
+        let client = match self.client {
+            Some(client) => client,
+            None => {
+                let mut builder = Client::builder()
+                    .danger_accept_invalid_certs(self.accept_invalid_certs);
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+                for certificate in self.root_certificates {
+                    builder = builder.add_root_certificate(certificate);
+                }
+                builder.build()?
+            }
+        };
+
+        Ok(PlexWebApi {
+            base_url: self.base_url,
+            auth_token: self.auth_token,
+            timeout: self.timeout,
+            identity: self.identity,
+            accept_invalid_certs: self.accept_invalid_certs,
+            machine_identifier: match self.machine_identifier {
+                Some(machine_identifier) => OnceLock::from(machine_identifier),
+                None => OnceLock::new(),
+            },
+            client,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use reqwest::Proxy;
+
+    use super::PlexWebApiBuilder;
+    use crate::web_api::ClientIdentity;
+
+    #[test]
+    fn test_build_with_transport_options() {
+        let api = PlexWebApiBuilder::new("https://127.0.0.1:32400", "token")
+            .timeout(Duration::from_secs(5))
+            .user_agent("plex-rs-test")
+            .proxy(Proxy::all("http://127.0.0.1:8080").unwrap())
+            .accept_invalid_certs(true)
+            .build();
+
+        assert!(api.is_ok());
+    }
+
+    #[test]
+    fn test_media_url_contains_identity() {
+        let api = PlexWebApiBuilder::new("http://127.0.0.1:32400", "token")
+            .identity(ClientIdentity::new("abc"))
+            .build()
+            .unwrap();
+
+        let url = api.media_url("/library/parts/1/file.mkv").unwrap();
+
+        assert!(url.starts_with("http://127.0.0.1:32400/library/parts/1/file.mkv?X-Plex-Token=token&"));
+        assert!(url.contains("X-Plex-Client-Identifier=abc"));
+    }
+}
@@ -0,0 +1,87 @@
+use super::error::Error;
+use super::models::{Collection, CollectionMode, CollectionSort, Collections, MediaContainer, MetadataContainer, MetadataType};
+use super::PlexWebApi;
+
+impl PlexWebApi {
+    pub async fn collections<S: Into<String>>(&self, section_id: S) -> Result<Collections, Error> {
+        let url = format!("{}/library/sections/{}/collections", self.base_url, section_id.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn collection_items<S: Into<String>>(&self, collection_id: S) -> Result<MetadataContainer, Error> {
+        let url = format!("{}/library/collections/{}/children", self.base_url, collection_id.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Creates a collection in the section containing the given items of `item_type`.
+    pub async fn create_collection<S: Into<String>, T: Into<String>>(&self, section_id: S, title: T, item_type: MetadataType, rating_keys: &[&str]) -> Result<Collection, Error> {
+        let uri = self.items_uri(rating_keys).await?;
+        let url = format!("{}/library/collections", self.base_url);
+        let request = self.api_post(&url)
+            .query(&[("type", item_type.id())])
+            .query(&[("title", title.into()), ("sectionId", section_id.into()), ("uri", uri)])
+            .query(&[("smart", "0")]);
+        let res: MediaContainer<Collections> = self.fetch(request).await?;
+
+        res.media_container.collections
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound { url })
+    }
+
+    pub async fn delete_collection<S: Into<String>>(&self, collection_id: S) -> Result<(), Error> {
+        self.send(self.api_delete(&format!("{}/library/collections/{}", self.base_url, collection_id.into()))).await?;
+
+        Ok(())
+    }
+
+    pub async fn add_to_collection<S: Into<String>>(&self, collection_id: S, rating_keys: &[&str]) -> Result<(), Error> {
+        let uri = self.items_uri(rating_keys).await?;
+        let request = self.api_put(&format!("{}/library/collections/{}/items", self.base_url, collection_id.into()))
+            .query(&[("uri", uri)]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_from_collection<C: Into<String>, R: Into<String>>(&self, collection_id: C, rating_key: R) -> Result<(), Error> {
+        let url = format!("{}/library/collections/{}/items/{}", self.base_url, collection_id.into(), rating_key.into());
+        self.send(self.api_delete(&url)).await?;
+
+        Ok(())
+    }
+
+    /// Moves an item behind the item `after`, `None` moves it to the top.
+    ///
+    /// Only has an effect with [`CollectionSort::Custom`].
+    pub async fn move_collection_item<C: Into<String>, R: Into<String>>(&self, collection_id: C, rating_key: R, after: Option<&str>) -> Result<(), Error> {
+        let url = format!("{}/library/collections/{}/items/{}/move", self.base_url, collection_id.into(), rating_key.into());
+        let mut request = self.api_put(&url);
+        if let Some(after) = after {
+            request = request.query(&[("after", after)]);
+        }
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    pub async fn set_collection_mode<S: Into<String>>(&self, collection_id: S, mode: CollectionMode) -> Result<(), Error> {
+        let request = self.api_put(&format!("{}/library/metadata/{}/prefs", self.base_url, collection_id.into()))
+            .query(&[("collectionMode", mode.id())]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    pub async fn set_collection_sort<S: Into<String>>(&self, collection_id: S, sort: CollectionSort) -> Result<(), Error> {
+        let request = self.api_put(&format!("{}/library/metadata/{}/prefs", self.base_url, collection_id.into()))
+            .query(&[("collectionSort", sort.id())]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+}
@@ -1,7 +1,72 @@
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
 use thiserror::Error;
 
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Http Error {0}")]
-    HttpError(#[from] reqwest::Error)
+    HttpError(#[from] reqwest::Error),
+    #[error("WebSocket Error {0}")]
+    WebSocketError(Box<tokio_tungstenite::tungstenite::Error>),
+    #[error("Unauthorized request to {url}, the token is missing or expired")]
+    Unauthorized { url: String },
+    #[error("Not found {url}")]
+    NotFound { url: String },
+    #[error("Client Error {status}: {body}")]
+    ClientError { status: StatusCode, body: String },
+    #[error("Server Error {status}: {body}")]
+    ServerError { status: StatusCode, body: String },
+    #[error("Response of {url} contains no section details")]
+    MissingSectionDetails { url: String },
+    #[error("Rating {rating} is out of range, ratings go from 0 to 10")]
+    InvalidRating { rating: u8 },
+    #[error("No items given")]
+    NoItems,
+    #[error("Filter {name} is not supported by the section")]
+    UnsupportedFilter { name: String },
+    #[error("Decode Error for {url} at {path}: {source}")]
+    Decode {
+        url: String,
+        /// Json path of the field which failed to deserialize, e.g. `MediaContainer.Metadata[3].year`
+        path: String,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for Error {
+    fn from(err: tokio_tungstenite::tungstenite::Error) -> Self {
+        Error::WebSocketError(Box::new(err))
+    }
+}
+
+pub(crate) fn decode<T: DeserializeOwned>(url: &str, body: &[u8]) -> Result<T, Error> {
+    let deserializer = &mut serde_json::Deserializer::from_slice(body);
+
+    serde_path_to_error::deserialize(deserializer).map_err(|err| Error::Decode {
+        url: url.to_string(),
+        path: err.path().to_string(),
+        source: err.into_inner(),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::{decode, Error};
+    use crate::web_api::models::{LibrarySections, MediaContainer};
+
+    #[test]
+    fn test_decode_error_contains_path() {
+        let body = br#"{"MediaContainer":{"size":1,"allowSync":false,"identifier":"com.plexapp.plugins.library","mediaTagPrefix":"/system/bundle/media/flags/","mediaTagVersion":"invalid","title1":"Plex Library","Directory":[]}}"#;
+
+        let res = decode::<MediaContainer<LibrarySections>>("/library/sections", body);
+
+        match res {
+            Err(Error::Decode { url, path, .. }) => {
+                assert_eq!(url, "/library/sections");
+                assert_eq!(path, "MediaContainer.mediaTagVersion");
+            }
+            res => panic!("expected decode error, got {:?}", res)
+        }
+    }
 }
@@ -0,0 +1,115 @@
+use super::section_query::SortDirection;
+
+/// Filters the watch history, all filters are optional.
+#[derive(Clone, Debug, Default)]
+pub struct HistoryQuery {
+    account_id: Option<i64>,
+    library_section_id: Option<i64>,
+    rating_key: Option<String>,
+    viewed_after: Option<u64>,
+    viewed_before: Option<u64>,
+    sort: Option<SortDirection>,
+    start: Option<u64>,
+    size: Option<u64>,
+}
+
+impl HistoryQuery {
+    pub fn new() -> Self {
+        HistoryQuery::default()
+    }
+
+    pub fn account(mut self, account_id: i64) -> Self {
+        self.account_id = Some(account_id);
+        self
+    }
+
+    pub fn library_section(mut self, library_section_id: i64) -> Self {
+        self.library_section_id = Some(library_section_id);
+        self
+    }
+
+    pub fn item<S: Into<String>>(mut self, rating_key: S) -> Self {
+        self.rating_key = Some(rating_key.into());
+        self
+    }
+
+    /// Unix timestamp in seconds, inclusive
+    pub fn viewed_after(mut self, timestamp: u64) -> Self {
+        self.viewed_after = Some(timestamp);
+        self
+    }
+
+    /// Unix timestamp in seconds, inclusive
+    pub fn viewed_before(mut self, timestamp: u64) -> Self {
+        self.viewed_before = Some(timestamp);
+        self
+    }
+
+    /// Sort by view date
+    pub fn sort(mut self, direction: SortDirection) -> Self {
+        self.sort = Some(direction);
+        self
+    }
+
+    pub fn page(mut self, start: u64, size: u64) -> Self {
+        self.start = Some(start);
+        self.size = Some(size);
+        self
+    }
+
+    pub(crate) fn paging(&self) -> Option<(u64, u64)> {
+        self.start.zip(self.size)
+    }
+
+    pub fn query_parameters(&self) -> Vec<(&'static str, String)> {
+        let mut parameters = Vec::new();
+        if let Some(account_id) = self.account_id {
+            parameters.push(("accountID", account_id.to_string()));
+        }
+        if let Some(library_section_id) = self.library_section_id {
+            parameters.push(("librarySectionID", library_section_id.to_string()));
+        }
+        if let Some(rating_key) = &self.rating_key {
+            parameters.push(("metadataItemID", rating_key.clone()));
+        }
+        if let Some(timestamp) = self.viewed_after {
+            parameters.push(("viewedAt>", timestamp.to_string()));
+        }
+        if let Some(timestamp) = self.viewed_before {
+            parameters.push(("viewedAt<", timestamp.to_string()));
+        }
+        match self.sort {
+            Some(SortDirection::Ascending) => parameters.push(("sort", "viewedAt".to_string())),
+            Some(SortDirection::Descending) => parameters.push(("sort", "viewedAt:desc".to_string())),
+            None => {}
+        }
+
+        parameters
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::HistoryQuery;
+    use crate::web_api::SortDirection;
+
+    #[test]
+    fn test_query_parameters() {
+        let query = HistoryQuery::new()
+            .account(1)
+            .library_section(2)
+            .viewed_after(1600000000)
+            .viewed_before(1700000000)
+            .sort(SortDirection::Descending)
+            .page(100, 50);
+
+        assert_eq!(query.query_parameters(), vec![
+            ("accountID", "1".to_string()),
+            ("librarySectionID", "2".to_string()),
+            ("viewedAt>", "1600000000".to_string()),
+            ("viewedAt<", "1700000000".to_string()),
+            ("sort", "viewedAt:desc".to_string()),
+        ]);
+        assert_eq!(query.paging(), Some((100, 50)));
+    }
+}
@@ -0,0 +1,54 @@
+use super::error::Error;
+use super::models::{Hubs, MediaContainer, MetadataContainer, SearchHubs};
+use super::PlexWebApi;
+
+impl PlexWebApi {
+    /// Hubs of the home screen across all sections.
+    pub async fn hubs(&self) -> Result<Hubs, Error> {
+        let res: MediaContainer<_> = self.fetch(self.api_get(&format!("{}/hubs", self.base_url))).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn section_hubs<S: Into<String>>(&self, section_id: S) -> Result<Hubs, Error> {
+        let url = format!("{}/hubs/sections/{}", self.base_url, section_id.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Next items to watch of in progress shows and partially watched items.
+    pub async fn on_deck(&self) -> Result<MetadataContainer, Error> {
+        let res: MediaContainer<_> = self.fetch(self.api_get(&format!("{}/hubs/home/onDeck", self.base_url))).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn continue_watching(&self) -> Result<Hubs, Error> {
+        let res: MediaContainer<_> = self.fetch(self.api_get(&format!("{}/hubs/continueWatching", self.base_url))).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn recently_added<S: Into<String>>(&self, section_id: S) -> Result<MetadataContainer, Error> {
+        let url = format!("{}/library/sections/{}/recentlyAdded", self.base_url, section_id.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Searches all or a single section, grouping the results per type.
+    ///
+    /// Requires `ServerInfo::hub_search`, `limit` applies to each group.
+    pub async fn hub_search<Q: Into<String>>(&self, query: Q, limit: u64, section_id: Option<&str>) -> Result<SearchHubs, Error> {
+        let mut request = self.api_get(&format!("{}/hubs/search", self.base_url))
+            .query(&[("query", query.into())])
+            .query(&[("limit", limit)]);
+        if let Some(section_id) = section_id {
+            request = request.query(&[("sectionId", section_id)]);
+        }
+        let res: MediaContainer<_> = self.fetch(request).await?;
+
+        Ok(res.media_container)
+    }
+}
@@ -0,0 +1,80 @@
+/// Identifies this client against the server.
+///
+/// Without these values the server lists the client as "Unknown" device.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClientIdentity {
+    /// Unique and stable identifier of this client instance, usually a uuid.
+    pub client_identifier: String,
+    pub product: String,
+    pub version: String,
+    pub platform: Option<String>,
+    pub platform_version: Option<String>,
+    pub device: Option<String>,
+    pub device_name: Option<String>,
+    pub model: Option<String>,
+    /// Comma separated list of capabilities, e.g. `player,controller`.
+    pub provides: Option<String>,
+}
+
+impl ClientIdentity {
+    /// Creates an identity for this crate, use struct update syntax to override values.
+    pub fn new<S: Into<String>>(client_identifier: S) -> Self {
+        ClientIdentity {
+            client_identifier: client_identifier.into(),
+            product: env!("CARGO_PKG_NAME").into(),
+            version: env!("CARGO_PKG_VERSION").into(),
+            platform: None,
+            platform_version: None,
+            device: None,
+            device_name: None,
+            model: None,
+            provides: None,
+        }
+    }
+
+    /// Values are sent as headers for api requests and as query parameters for media urls.
+    pub(crate) fn parameters(&self) -> Vec<(&'static str, &str)> {
+        let mut parameters = vec![
+            ("X-Plex-Client-Identifier", self.client_identifier.as_str()),
+            ("X-Plex-Product", self.product.as_str()),
+            ("X-Plex-Version", self.version.as_str()),
+        ];
+        let optional = [
+            ("X-Plex-Platform", &self.platform),
+            ("X-Plex-Platform-Version", &self.platform_version),
+            ("X-Plex-Device", &self.device),
+            ("X-Plex-Device-Name", &self.device_name),
+            ("X-Plex-Model", &self.model),
+            ("X-Plex-Provides", &self.provides),
+        ];
+        for (name, value) in optional.iter() {
+            if let Some(value) = value {
+                parameters.push((name, value.as_str()));
+            }
+        }
+
+        parameters
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ClientIdentity;
+
+    #[test]
+    fn test_parameters_skip_missing_values() {
+        let identity = ClientIdentity {
+            device_name: Some("Living Room".into()),
+            ..ClientIdentity::new("abc")
+        };
+
+        let parameters = identity.parameters();
+
+        assert_eq!(parameters, vec![
+            ("X-Plex-Client-Identifier", "abc"),
+            ("X-Plex-Product", env!("CARGO_PKG_NAME")),
+            ("X-Plex-Version", env!("CARGO_PKG_VERSION")),
+            ("X-Plex-Device-Name", "Living Room"),
+        ]);
+    }
+}
@@ -0,0 +1,168 @@
+use super::error::Error;
+use super::models::{DirectoryType, SectionDirectory};
+use super::PlexWebApi;
+
+/// Changes to an existing section, created from its current settings.
+#[derive(Clone, Debug)]
+pub struct SectionEdit {
+    name: String,
+    agent: String,
+    language: String,
+    locations: Vec<String>,
+}
+
+impl SectionEdit {
+    pub fn new(section: &SectionDirectory) -> Self {
+        SectionEdit {
+            name: section.title.clone(),
+            agent: section.agent.clone(),
+            language: section.language.clone(),
+            locations: section.location.iter().map(|location| location.path.clone()).collect(),
+        }
+    }
+
+    pub fn name<S: Into<String>>(mut self, name: S) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    pub fn agent<S: Into<String>>(mut self, agent: S) -> Self {
+        self.agent = agent.into();
+        self
+    }
+
+    pub fn language<S: Into<String>>(mut self, language: S) -> Self {
+        self.language = language.into();
+        self
+    }
+
+    pub fn add_location<S: Into<String>>(mut self, path: S) -> Self {
+        let path = path.into();
+        if !self.locations.contains(&path) {
+            self.locations.push(path);
+        }
+        self
+    }
+
+    pub fn remove_location(mut self, path: &str) -> Self {
+        self.locations.retain(|location| location != path);
+        self
+    }
+
+    fn query_parameters(&self) -> Vec<(&'static str, &str)> {
+        let mut parameters = vec![
+            ("name", self.name.as_str()),
+            ("agent", self.agent.as_str()),
+            ("language", self.language.as_str()),
+        ];
+        for location in &self.locations {
+            parameters.push(("location", location.as_str()));
+        }
+
+        parameters
+    }
+}
+
+impl PlexWebApi {
+    /// Creates a section, `agent` and `scanner` have to support the section type.
+    pub async fn create_section<N: Into<String>>(&self, name: N, section_type: DirectoryType, agent: &str, scanner: &str, language: &str, locations: &[&str]) -> Result<(), Error> {
+        let mut request = self.api_post(&format!("{}/library/sections", self.base_url))
+            .query(&[("name", name.into())])
+            .query(&[("type", section_type)])
+            .query(&[("agent", agent), ("scanner", scanner), ("language", language)]);
+        for location in locations {
+            request = request.query(&[("location", location)]);
+        }
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    pub async fn edit_section<S: Into<String>>(&self, section_id: S, edit: &SectionEdit) -> Result<(), Error> {
+        let request = self.api_put(&format!("{}/library/sections/{}", self.base_url, section_id.into()))
+            .query(&edit.query_parameters());
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Deletes the section including the metadata of all its items, the media files are kept.
+    pub async fn delete_section<S: Into<String>>(&self, section_id: S) -> Result<(), Error> {
+        self.send(self.api_delete(&format!("{}/library/sections/{}", self.base_url, section_id.into()))).await?;
+
+        Ok(())
+    }
+
+    /// Scans the locations of the section for changes, `force` refreshes the metadata of all items.
+    pub async fn refresh_section<S: Into<String>>(&self, section_id: S, force: bool) -> Result<(), Error> {
+        let mut request = self.api_get(&format!("{}/library/sections/{}/refresh", self.base_url, section_id.into()));
+        if force {
+            request = request.query(&[("force", "1")]);
+        }
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Scans a single directory, the path has to be inside one of the `Location`s of the section.
+    pub async fn scan_path<S: Into<String>, P: Into<String>>(&self, section_id: S, path: P) -> Result<(), Error> {
+        let request = self.api_get(&format!("{}/library/sections/{}/refresh", self.base_url, section_id.into()))
+            .query(&[("path", path.into())]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    pub async fn cancel_refresh<S: Into<String>>(&self, section_id: S) -> Result<(), Error> {
+        self.send(self.api_delete(&format!("{}/library/sections/{}/refresh", self.base_url, section_id.into()))).await?;
+
+        Ok(())
+    }
+
+    /// Analyzes the media of all items in the section.
+    pub async fn analyze_section<S: Into<String>>(&self, section_id: S) -> Result<(), Error> {
+        self.send(self.api_put(&format!("{}/library/sections/{}/analyze", self.base_url, section_id.into()))).await?;
+
+        Ok(())
+    }
+
+    /// Removes items whose files are no longer available.
+    pub async fn empty_trash<S: Into<String>>(&self, section_id: S) -> Result<(), Error> {
+        self.send(self.api_put(&format!("{}/library/sections/{}/emptyTrash", self.base_url, section_id.into()))).await?;
+
+        Ok(())
+    }
+
+    /// Deletes metadata bundles of items which are no longer part of any section.
+    pub async fn clean_bundles(&self) -> Result<(), Error> {
+        self.send(self.api_put(&format!("{}/library/clean/bundles", self.base_url))).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SectionEdit;
+    use crate::web_api::models::SectionDirectory;
+
+    #[test]
+    fn test_section_edit_locations() {
+        let json = r#"{"allowSync":true,"art":"","composite":"","filters":true,"refreshing":false,"thumb":"","key":"1","type":"movie","title":"Movies","agent":"tv.plex.agents.movie","scanner":"Plex Movie","language":"en-US","uuid":"abc","createdAt":1,"content":true,"directory":true,"contentChangedAt":1,"hidden":0,"Location":[{"id":1,"path":"/media/movies"},{"id":2,"path":"/media/old"}]}"#;
+        let section: SectionDirectory = serde_json::from_str(json).unwrap();
+
+        let edit = SectionEdit::new(&section)
+            .name("Films")
+            .add_location("/media/films")
+            .add_location("/media/movies")
+            .remove_location("/media/old");
+
+        assert_eq!(edit.query_parameters(), vec![
+            ("name", "Films"),
+            ("agent", "tv.plex.agents.movie"),
+            ("language", "en-US"),
+            ("location", "/media/movies"),
+            ("location", "/media/films"),
+        ]);
+    }
+}
@@ -0,0 +1,47 @@
+use super::error::Error;
+use super::models::{MatchCandidates, MediaContainer};
+use super::PlexWebApi;
+
+impl PlexWebApi {
+    /// Searches the agent for items the given item could be matched to.
+    ///
+    /// Without `title` and `year` the values of the item are used, `agent` defaults to the agent of the section.
+    pub async fn match_candidates<S: Into<String>>(&self, rating_key: S, title: Option<&str>, year: Option<u64>, agent: Option<&str>) -> Result<MatchCandidates, Error> {
+        let mut request = self.api_get(&format!("{}/library/metadata/{}/matches", self.base_url, rating_key.into()))
+            .query(&[("manual", "1")]);
+        if let Some(title) = title {
+            request = request.query(&[("title", title)]);
+        }
+        if let Some(year) = year {
+            request = request.query(&[("year", year)]);
+        }
+        if let Some(agent) = agent {
+            request = request.query(&[("agent", agent)]);
+        }
+        let res: MediaContainer<_> = self.fetch(request).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Matches the item to a candidate returned by [`PlexWebApi::match_candidates`].
+    pub async fn apply_match<S: Into<String>, G: Into<String>, N: Into<String>>(&self, rating_key: S, guid: G, name: N) -> Result<(), Error> {
+        let request = self.api_put(&format!("{}/library/metadata/{}/match", self.base_url, rating_key.into()))
+            .query(&[("guid", guid.into()), ("name", name.into())]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    pub async fn unmatch<S: Into<String>>(&self, rating_key: S) -> Result<(), Error> {
+        self.send(self.api_put(&format!("{}/library/metadata/{}/unmatch", self.base_url, rating_key.into()))).await?;
+
+        Ok(())
+    }
+
+    /// Fetches the agent data of the item again, this happens in the background.
+    pub async fn refresh_metadata<S: Into<String>>(&self, rating_key: S) -> Result<(), Error> {
+        self.send(self.api_put(&format!("{}/library/metadata/{}/refresh", self.base_url, rating_key.into()))).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,260 @@
+use super::error::Error;
+use super::models::MetadataType;
+use super::PlexWebApi;
+
+/// Editable attributes, named like the fields of the metadata models.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetadataField {
+    Title,
+    TitleSort,
+    OriginalTitle,
+    Summary,
+    Tagline,
+    Year,
+    OriginallyAvailableAt,
+    ContentRating,
+    Studio,
+}
+
+impl MetadataField {
+    fn key(&self) -> &'static str {
+        match self {
+            MetadataField::Title => "title",
+            MetadataField::TitleSort => "titleSort",
+            MetadataField::OriginalTitle => "originalTitle",
+            MetadataField::Summary => "summary",
+            MetadataField::Tagline => "tagline",
+            MetadataField::Year => "year",
+            MetadataField::OriginallyAvailableAt => "originallyAvailableAt",
+            MetadataField::ContentRating => "contentRating",
+            MetadataField::Studio => "studio",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TagField {
+    Genre,
+    Collection,
+    Label,
+    Director,
+    Writer,
+    Country,
+    Role,
+}
+
+impl TagField {
+    fn key(&self) -> &'static str {
+        match self {
+            TagField::Genre => "genre",
+            TagField::Collection => "collection",
+            TagField::Label => "label",
+            TagField::Director => "director",
+            TagField::Writer => "writer",
+            TagField::Country => "country",
+            TagField::Role => "actor",
+        }
+    }
+}
+
+/// Changes to the metadata of a single item.
+///
+/// Edited fields are locked, so the agent doesn't overwrite them on the next refresh.
+///
+/// ```
+/// use plex_rs::web_api::{MetadataEdit, TagField};
+/// use plex_rs::web_api::models::MetadataType;
+///
+/// let edit = MetadataEdit::new(MetadataType::Movie, "1337")
+///     .title("Alien")
+///     .year(1979)
+///     .set_tags(TagField::Genre, &["Horror", "Science Fiction"])
+///     .remove_tags(TagField::Label, &["Unsorted"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct MetadataEdit {
+    item_type: MetadataType,
+    rating_key: String,
+    values: Vec<(MetadataField, String)>,
+    tags: Vec<(TagField, Vec<String>)>,
+    removed_tags: Vec<(TagField, Vec<String>)>,
+    locks: Vec<(&'static str, bool)>,
+}
+
+impl MetadataEdit {
+    pub fn new<S: Into<String>>(item_type: MetadataType, rating_key: S) -> Self {
+        MetadataEdit {
+            item_type,
+            rating_key: rating_key.into(),
+            values: Vec::new(),
+            tags: Vec::new(),
+            removed_tags: Vec::new(),
+            locks: Vec::new(),
+        }
+    }
+
+    pub fn set<V: ToString>(mut self, field: MetadataField, value: V) -> Self {
+        self.values.push((field, value.to_string()));
+        self.locks.push((field.key(), true));
+        self
+    }
+
+    pub fn title<S: Into<String>>(self, title: S) -> Self {
+        self.set(MetadataField::Title, title.into())
+    }
+
+    pub fn title_sort<S: Into<String>>(self, title_sort: S) -> Self {
+        self.set(MetadataField::TitleSort, title_sort.into())
+    }
+
+    pub fn original_title<S: Into<String>>(self, original_title: S) -> Self {
+        self.set(MetadataField::OriginalTitle, original_title.into())
+    }
+
+    pub fn summary<S: Into<String>>(self, summary: S) -> Self {
+        self.set(MetadataField::Summary, summary.into())
+    }
+
+    pub fn tagline<S: Into<String>>(self, tagline: S) -> Self {
+        self.set(MetadataField::Tagline, tagline.into())
+    }
+
+    pub fn year(self, year: u64) -> Self {
+        self.set(MetadataField::Year, year)
+    }
+
+    /// Date formatted as `YYYY-MM-DD`
+    pub fn originally_available_at<S: Into<String>>(self, date: S) -> Self {
+        self.set(MetadataField::OriginallyAvailableAt, date.into())
+    }
+
+    pub fn content_rating<S: Into<String>>(self, content_rating: S) -> Self {
+        self.set(MetadataField::ContentRating, content_rating.into())
+    }
+
+    pub fn studio<S: Into<String>>(self, studio: S) -> Self {
+        self.set(MetadataField::Studio, studio.into())
+    }
+
+    /// Replaces all tags of the field, tags of the item missing in `tags` are removed.
+    ///
+    /// Use [`MetadataEdit::remove_tags`] to remove single tags and keep the others.
+    /// An empty list only locks the field, the server needs the tags to remove them.
+    pub fn set_tags(mut self, field: TagField, tags: &[&str]) -> Self {
+        self.tags.retain(|(tag_field, _)| *tag_field != field);
+        self.tags.push((field, tags.iter().map(|tag| tag.to_string()).collect()));
+        self.locks.push((field.key(), true));
+        self
+    }
+
+    pub fn remove_tags(mut self, field: TagField, tags: &[&str]) -> Self {
+        let tags = tags.iter().map(|tag| tag.to_string());
+        match self.removed_tags.iter_mut().find(|(tag_field, _)| *tag_field == field) {
+            Some((_, removed)) => removed.extend(tags),
+            None => self.removed_tags.push((field, tags.collect())),
+        }
+        self.locks.push((field.key(), true));
+        self
+    }
+
+    pub fn lock(mut self, field: MetadataField) -> Self {
+        self.locks.push((field.key(), true));
+        self
+    }
+
+    /// Allows the agent to update the field again
+    pub fn unlock(mut self, field: MetadataField) -> Self {
+        self.locks.push((field.key(), false));
+        self
+    }
+
+    pub fn lock_tags(mut self, field: TagField) -> Self {
+        self.locks.push((field.key(), true));
+        self
+    }
+
+    pub fn unlock_tags(mut self, field: TagField) -> Self {
+        self.locks.push((field.key(), false));
+        self
+    }
+
+    fn query_parameters(&self) -> Vec<(String, String)> {
+        let mut parameters = vec![
+            ("type".to_string(), self.item_type.id().to_string()),
+            ("id".to_string(), self.rating_key.clone()),
+        ];
+        for (field, value) in &self.values {
+            parameters.push((format!("{}.value", field.key()), value.clone()));
+        }
+        for (field, tags) in &self.tags {
+            for (index, tag) in tags.iter().enumerate() {
+                parameters.push((format!("{}[{}].tag.tag", field.key(), index), tag.clone()));
+            }
+        }
+        for (field, tags) in &self.removed_tags {
+            // tags are encoded on their own, so commas within a tag don't split it
+            let tags = tags.iter()
+                .map(|tag| url::form_urlencoded::byte_serialize(tag.as_bytes()).collect::<String>().replace('+', "%20"))
+                .collect::<Vec<_>>()
+                .join(",");
+            parameters.push((format!("{}[].tag.tag-", field.key()), tags));
+        }
+        let mut locks: Vec<(&str, bool)> = Vec::new();
+        for (key, locked) in &self.locks {
+            // the last call for a field wins
+            locks.retain(|(lock_key, _)| lock_key != key);
+            locks.push((key, *locked));
+        }
+        for (key, locked) in locks {
+            parameters.push((format!("{}.locked", key), (locked as u8).to_string()));
+        }
+
+        parameters
+    }
+}
+
+impl PlexWebApi {
+    /// Applies the changes to an item of the section.
+    pub async fn edit_metadata<S: Into<String>>(&self, section_id: S, edit: &MetadataEdit) -> Result<(), Error> {
+        let request = self.api_put(&format!("{}/library/sections/{}/all", self.base_url, section_id.into()))
+            .query(&edit.query_parameters());
+        self.send(request).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MetadataEdit, MetadataField, TagField};
+    use crate::web_api::models::MetadataType;
+
+    #[test]
+    fn test_query_parameters() {
+        let edit = MetadataEdit::new(MetadataType::Movie, "1337")
+            .title("Alien")
+            .year(1979)
+            .set_tags(TagField::Genre, &["Drama"])
+            .set_tags(TagField::Genre, &["Horror", "Science Fiction"])
+            .remove_tags(TagField::Collection, &["Unsorted"])
+            .remove_tags(TagField::Collection, &["Action, Adventure"])
+            .unlock(MetadataField::Year);
+
+        let parameters = edit.query_parameters();
+
+        let expected: Vec<(String, String)> = vec![
+            ("type", "1"),
+            ("id", "1337"),
+            ("title.value", "Alien"),
+            ("year.value", "1979"),
+            ("genre[0].tag.tag", "Horror"),
+            ("genre[1].tag.tag", "Science Fiction"),
+            ("collection[].tag.tag-", "Unsorted,Action%2C%20Adventure"),
+            ("title.locked", "1"),
+            ("genre.locked", "1"),
+            ("collection.locked", "1"),
+            ("year.locked", "0"),
+        ].into_iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+        assert_eq!(parameters, expected);
+    }
+}
@@ -1,15 +1,50 @@
-use reqwest::{Client, RequestBuilder};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use futures::{stream, Stream, TryStreamExt};
+use reqwest::{Client, Method, RequestBuilder, Response, StatusCode};
+use serde::de::DeserializeOwned;
 
 use self::error::Error;
 use self::models::*;
 
+pub use self::builder::PlexWebApiBuilder;
+pub use self::history_query::HistoryQuery;
+pub use self::identity::ClientIdentity;
+pub use self::library::SectionEdit;
+pub use self::metadata_edit::{MetadataEdit, MetadataField, TagField};
+pub use self::playback::TimelineReporter;
+pub use self::play_queues::{PlayQueueOptions, PlayQueueSource};
+pub use self::section_query::{Filter, FilterOperator, SectionQuery, SortDirection};
+
 pub mod models;
 pub mod error;
+mod builder;
+mod collections;
+mod history_query;
+mod hubs;
+mod identity;
+mod library;
+mod matching;
+mod metadata_edit;
+mod notifications;
+mod playback;
+mod play_queues;
+mod playlists;
+mod section_query;
+mod sessions;
+
+const LIBRARY_IDENTIFIER: &str = "com.plexapp.plugins.library";
 
 #[derive(Debug, Clone)]
 pub struct PlexWebApi {
     base_url: String,
     auth_token: String,
+    timeout: Option<Duration>,
+    identity: Option<ClientIdentity>,
+    accept_invalid_certs: bool,
+    /// Fetched on first use by [`PlexWebApi::library_uri`]
+    machine_identifier: OnceLock<String>,
     client: Client
 }
 
@@ -18,66 +53,280 @@ impl PlexWebApi {
         PlexWebApi {
             base_url: base_url.into(),
             auth_token: auth_token.into(),
+            timeout: None,
+            identity: None,
+            accept_invalid_certs: false,
+            machine_identifier: OnceLock::new(),
             client: Client::new()
         }
     }
 
+    pub fn builder<S: Into<String>>(base_url: S, auth_token: S) -> PlexWebApiBuilder {
+        PlexWebApiBuilder::new(base_url, auth_token)
+    }
+
     pub async fn server_information(&self) -> Result<ServerInfo, Error> {
-        let res: MediaContainer<_> = self.api_get(&format!("{}", self.base_url))
-            .send()
-            .await?
-            .error_for_status()?
-            .json()
-            .await?;
+        let res: MediaContainer<_> = self.fetch(self.api_get(&self.base_url)).await?;
 
         Ok(res.media_container)
     }
 
     pub async fn library_sections(&self) -> Result<LibrarySections, Error> {
-        let res: MediaContainer<_> = self.api_get(&format!("{}/library/sections", self.base_url))
-            .send()
-            .await?
-            .error_for_status()?
-            .json()
-            .await?;
+        let res: MediaContainer<_> = self.fetch(self.api_get(&format!("{}/library/sections", self.base_url))).await?;
 
         Ok(res.media_container)
     }
 
     pub async fn library_section<S: Into<String>>(&self, section: S) -> Result<LibrarySection, Error> {
-        let res: MediaContainer<_> = self.api_get(&format!("{}/library/sections/{}", self.base_url, section.into()))
-            .send()
-            .await?
-            .error_for_status()?
-            .json()
-            .await?;
+        let res: MediaContainer<_> = self.fetch(self.api_get(&format!("{}/library/sections/{}", self.base_url, section.into()))).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn section_filters<S: Into<String>>(&self, section_id: S) -> Result<SectionDefinitions<FilterDefinition>, Error> {
+        let url = format!("{}/library/sections/{}/filters", self.base_url, section_id.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn section_sorts<S: Into<String>>(&self, section_id: S) -> Result<SectionDefinitions<SortDefinition>, Error> {
+        let url = format!("{}/library/sections/{}/sorts", self.base_url, section_id.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Filters, sorts and fields per item type of the section.
+    ///
+    /// Fails with [`Error::MissingSectionDetails`] when the server doesn't include them.
+    pub async fn section_meta<S: Into<String>>(&self, section_id: S) -> Result<SectionMeta, Error> {
+        let url = format!("{}/library/sections/{}", self.base_url, section_id.into());
+        let request = self.api_get(&url)
+            .query(&[("includeDetails", "1")]);
+        let res: MediaContainer<LibrarySection> = self.fetch(request).await?;
+
+        res.media_container.meta.ok_or(Error::MissingSectionDetails { url })
+    }
+
+    /// Streams all items of a section, fetching `page_size` items per request.
+    pub fn library_section_items<S: Into<String>>(&self, section_id: S, page_size: u64) -> impl Stream<Item = Result<Metadatum, Error>> + '_ {
+        self.query_section_items(section_id, &SectionQuery::default(), page_size)
+    }
+
+    pub async fn query_section<S: Into<String>>(&self, section_id: S, query: &SectionQuery) -> Result<LibrarySection, Error> {
+        let request = self.api_get(&format!("{}/library/sections/{}/all", self.base_url, section_id.into()))
+            .query(&query.query_parameters());
+        let res: MediaContainer<_> = self.fetch(request).await?;
 
         Ok(res.media_container)
     }
 
+    /// Streams the items matching the query, fetching `page_size` items per request.
+    pub fn query_section_items<S: Into<String>>(&self, section_id: S, query: &SectionQuery, page_size: u64) -> impl Stream<Item = Result<Metadatum, Error>> + '_ {
+        let url = format!("{}/library/sections/{}/all", self.base_url, section_id.into());
+
+        self.paginate(url, query.query_parameters(), page_size)
+    }
+
+    /// Fetches a single item including its media, extras, chapters and markers.
+    pub async fn metadata<S: Into<String>>(&self, rating_key: S) -> Result<Metadatum, Error> {
+        let url = format!("{}/library/metadata/{}", self.base_url, rating_key.into());
+        let request = self.api_get(&url)
+            .query(&[("includeExtras", "1"), ("includeChapters", "1"), ("includeMarkers", "1")]);
+        let res: MediaContainer<MetadataContainer> = self.fetch(request).await?;
+
+        res.media_container.metadata
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound { url })
+    }
+
+    /// Direct children of an item, e.g. the seasons of a show or the tracks of an album.
+    pub async fn children<S: Into<String>>(&self, rating_key: S) -> Result<MetadataContainer, Error> {
+        let url = format!("{}/library/metadata/{}/children", self.base_url, rating_key.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// All leaves below an item, e.g. every episode of a show or every track of an artist.
+    pub async fn all_leaves<S: Into<String>>(&self, rating_key: S) -> Result<MetadataContainer, Error> {
+        let url = format!("{}/library/metadata/{}/allLeaves", self.base_url, rating_key.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Selects the audio and subtitle stream of a part for all devices.
+    ///
+    /// `None` keeps the current selection, a subtitle stream id of `0` disables subtitles.
+    pub async fn set_streams(&self, part_id: u64, audio_stream_id: Option<u64>, subtitle_stream_id: Option<u64>) -> Result<(), Error> {
+        let mut request = self.api_put(&format!("{}/library/parts/{}", self.base_url, part_id))
+            .query(&[("allParts", "1")]);
+        if let Some(id) = audio_stream_id {
+            request = request.query(&[("audioStreamID", id)]);
+        }
+        if let Some(id) = subtitle_stream_id {
+            request = request.query(&[("subtitleStreamID", id)]);
+        }
+        self.send(request).await?;
+
+        Ok(())
+    }
+
     pub async fn search<Q: Into<String>>(&self, query: Q) -> Result<SearchResults, Error> {
-        let res: MediaContainer<_> = self.api_get(&format!("{}/search", self.base_url))
-            .query(&[("query", query.into())])
-            .send()
-            .await?
-            .error_for_status()?
-            .json()
-            .await?;
+        let request = self.api_get(&format!("{}/search", self.base_url))
+            .query(&[("query", query.into())]);
+        let res: MediaContainer<_> = self.fetch(request).await?;
 
         Ok(res.media_container)
     }
 
+    /// Builds an absolute url for a server relative path like `Part::key` or a transcoder endpoint.
+    ///
+    /// Players can't attach headers, so the token and client identity are added as query parameters.
+    pub fn media_url(&self, path: &str) -> Result<String, Error> {
+        let mut request = self.client.get(format!("{}{}", self.base_url, path))
+            .query(&[("X-Plex-Token", &self.auth_token)]);
+        if let Some(identity) = &self.identity {
+            request = request.query(&identity.parameters());
+        }
+        let request = request.build()?;
+
+        Ok(request.url().to_string())
+    }
+
+    fn paginate(&self, url: String, query: Vec<(String, String)>, page_size: u64) -> impl Stream<Item = Result<Metadatum, Error>> + '_ {
+        stream::try_unfold((url, query, Some(0)), move |(url, query, offset)| async move {
+            match offset {
+                Some(offset) => self.fetch_page(&url, &query, offset, page_size).await
+                    .map(|(items, next)| Some((items, (url, query, next)))),
+                None => Ok(None)
+            }
+        })
+        .map_ok(|items| stream::iter(items.into_iter().map(Ok)))
+        .try_flatten()
+    }
+
+    async fn fetch_page(&self, url: &str, query: &[(String, String)], offset: u64, page_size: u64) -> Result<(Vec<Metadatum>, Option<u64>), Error> {
+        let request = self.api_get(url)
+            .query(query)
+            .header("X-Plex-Container-Start", offset)
+            .header("X-Plex-Container-Size", page_size);
+        let res: MediaContainer<LibrarySection> = self.fetch(request).await?;
+        let page = res.media_container;
+        let total_size = page.paging.as_ref().map(|paging| paging.total_size);
+        let next = next_offset(offset, page.metadata.len() as u64, total_size);
+
+        Ok((page.metadata, next))
+    }
+
+    /// Uri referencing library content of this server, e.g. to add items to a playlist.
+    async fn library_uri(&self, path: &str) -> Result<String, Error> {
+        let machine_identifier = match self.machine_identifier.get() {
+            Some(machine_identifier) => machine_identifier,
+            None => {
+                let server = self.server_information().await?;
+                self.machine_identifier.get_or_init(|| server.machine_identifier)
+            }
+        };
+
+        Ok(library_uri(machine_identifier, path))
+    }
+
+    async fn items_uri(&self, rating_keys: &[&str]) -> Result<String, Error> {
+        if rating_keys.is_empty() {
+            return Err(Error::NoItems);
+        }
+
+        self.library_uri(&format!("/library/metadata/{}", rating_keys.join(","))).await
+    }
+
+    /// Sends the request and maps error status codes to the matching [`Error`] variant.
+    async fn send(&self, request: RequestBuilder) -> Result<Response, Error> {
+        let response = request.send().await?;
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let url = response.url().to_string();
+        log::debug!("Request to {} failed with status {}", url, status);
+
+        match status {
+            StatusCode::UNAUTHORIZED => Err(Error::Unauthorized { url }),
+            StatusCode::NOT_FOUND => Err(Error::NotFound { url }),
+            status if status.is_server_error() => Err(Error::ServerError {
+                status,
+                body: response.text().await?
+            }),
+            status => Err(Error::ClientError {
+                status,
+                body: response.text().await?
+            })
+        }
+    }
+
+    async fn fetch<T: DeserializeOwned>(&self, request: RequestBuilder) -> Result<T, Error> {
+        let response = self.send(request).await?;
+        let url = response.url().to_string();
+        let body = response.bytes().await?;
+
+        error::decode(&url, &body)
+    }
+
     fn api_get(&self, url: &str) -> RequestBuilder {
-        self.client.get(url)
+        self.api_request(Method::GET, url)
+    }
+
+    fn api_put(&self, url: &str) -> RequestBuilder {
+        self.api_request(Method::PUT, url)
+    }
+
+    fn api_post(&self, url: &str) -> RequestBuilder {
+        self.api_request(Method::POST, url)
+    }
+
+    fn api_delete(&self, url: &str) -> RequestBuilder {
+        self.api_request(Method::DELETE, url)
+    }
+
+    fn api_request(&self, method: Method, url: &str) -> RequestBuilder {
+        let mut request = self.client.request(method, url)
             .header("X-Plex-Token", &self.auth_token)
-            .header("Accept", "application/json")
+            .header("Accept", "application/json");
+        if let Some(identity) = &self.identity {
+            for (name, value) in identity.parameters() {
+                request = request.header(name, value);
+            }
+        }
+
+        match self.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request
+        }
+    }
+}
+
+fn library_uri(machine_identifier: &str, path: &str) -> String {
+    format!("server://{}/{}{}", machine_identifier, LIBRARY_IDENTIFIER, path)
+}
+
+fn next_offset(offset: u64, received: u64, total_size: Option<u64>) -> Option<u64> {
+    let next = offset + received;
+    match total_size {
+        _ if received == 0 => None,
+        Some(total_size) if next >= total_size => None,
+        _ => Some(next)
     }
 }
 
 #[cfg(test)]
 mod test {
-    use super::PlexWebApi;
-    use crate::web_api::models::Directory;
+    use futures::TryStreamExt;
+
+    use super::{next_offset, Error, HistoryQuery, PlexWebApi, SortDirection};
+    use crate::web_api::models::{Directory, Metadatum};
 
     fn create_api() -> PlexWebApi {
         let url = env!("PLEX_BASE_URL");
@@ -96,6 +345,84 @@ mod test {
         assert!(res.is_ok());
     }
 
+    #[tokio::test]
+    async fn test_sessions() {
+        let api = create_api();
+
+        let res = api.sessions().await;
+
+        println!("{:?}", res);
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_history() {
+        let api = create_api();
+
+        let res = api.history(&HistoryQuery::new().sort(SortDirection::Descending).page(0, 50)).await;
+
+        println!("{:?}", res);
+        assert!(res.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_hubs() {
+        let api = create_api();
+
+        let hubs = api.hubs().await;
+        let on_deck = api.on_deck().await;
+        let continue_watching = api.continue_watching().await;
+
+        println!("{:?} {:?} {:?}", hubs, on_deck, continue_watching);
+        assert!(hubs.is_ok());
+        assert!(on_deck.is_ok());
+        assert!(continue_watching.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_section_hubs() {
+        let api = create_api();
+
+        let sections = api.library_sections().await.unwrap();
+        for directory in sections.directories {
+            let hubs = api.section_hubs(directory.key()).await;
+            let recently_added = api.recently_added(directory.key()).await;
+
+            println!("{:?} {:?}", hubs, recently_added);
+            assert!(hubs.is_ok());
+            assert!(recently_added.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_playlists() {
+        let api = create_api();
+
+        let playlists = api.playlists().await.unwrap();
+        for playlist in playlists.playlists {
+            let res = api.playlist_items(playlist.rating_key.as_str()).await;
+
+            println!("{:?}", res);
+            assert!(res.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_collections() {
+        let api = create_api();
+
+        let sections = api.library_sections().await.unwrap();
+        for directory in sections.directories {
+            let collections = api.collections(directory.key()).await.unwrap();
+            for collection in collections.collections {
+                let res = api.collection_items(collection.rating_key.as_str()).await;
+
+                println!("{:?}", res);
+                assert!(res.is_ok());
+            }
+        }
+    }
+
     #[tokio::test]
     async fn test_library_sections() {
         let api = create_api();
@@ -106,6 +433,113 @@ mod test {
         assert!(res.is_ok());
     }
 
+    #[test]
+    fn test_next_offset() {
+        assert_eq!(next_offset(0, 100, Some(250)), Some(100));
+        assert_eq!(next_offset(200, 50, Some(250)), None);
+        assert_eq!(next_offset(0, 100, None), Some(100));
+        assert_eq!(next_offset(100, 0, None), None);
+    }
+
+    #[tokio::test]
+    async fn test_items_uri() {
+        let api = PlexWebApi::builder("http://127.0.0.1:9", "token")
+            .machine_identifier("abc")
+            .build()
+            .unwrap();
+
+        let uri = api.items_uri(&["1", "2"]).await.unwrap();
+
+        assert_eq!(uri, "server://abc/com.plexapp.plugins.library/library/metadata/1,2");
+        assert!(matches!(api.items_uri(&[]).await, Err(Error::NoItems)));
+    }
+
+    #[tokio::test]
+    async fn test_library_section_items() {
+        let api = create_api();
+
+        let sections = api.library_sections().await.unwrap();
+        for directory in sections.directories {
+            let section = api.library_section(format!("{}/all", directory.key())).await.unwrap();
+            let items: Vec<_> = api.library_section_items(directory.key(), 50).try_collect().await.unwrap();
+
+            assert_eq!(items.len(), section.metadata.len());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_section_definitions() {
+        let api = create_api();
+
+        let sections = api.library_sections().await.unwrap();
+        for directory in sections.directories {
+            let filters = api.section_filters(directory.key()).await;
+            let sorts = api.section_sorts(directory.key()).await;
+            let meta = api.section_meta(directory.key()).await;
+
+            println!("{:?} {:?} {:?}", filters, sorts, meta);
+            assert!(filters.is_ok());
+            assert!(sorts.is_ok());
+            assert!(meta.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metadata() {
+        let api = create_api();
+
+        let sections = api.library_sections().await.unwrap();
+        let mut errors = 0u64;
+        for directory in sections.directories {
+            let section = api.library_section(format!("{}/all", directory.key())).await.unwrap();
+            for rating_key in section.metadata.iter().take(5).filter_map(|item| item.rating_key()) {
+                let res = api.metadata(rating_key).await;
+                if res.is_err() {
+                    println!("{} => {:?}", rating_key, res);
+                    errors += 1;
+                }
+            }
+        }
+
+        assert_eq!(errors, 0);
+    }
+
+    #[tokio::test]
+    async fn test_children_traversal() {
+        let api = create_api();
+
+        let sections = api.library_sections().await.unwrap();
+        let mut errors = 0u64;
+        for directory in sections.directories {
+            let section = api.library_section(format!("{}/all", directory.key())).await.unwrap();
+            for item in section.metadata.iter().take(5) {
+                let rating_key = match item {
+                    Metadatum::Show(show) => &show.rating_key,
+                    Metadatum::Artist(artist) => &artist.rating_key,
+                    _ => continue
+                };
+                let res = api.children(rating_key.as_str()).await;
+                if let Ok(children) = res {
+                    for child in children.metadata.iter().filter_map(|child| child.rating_key()) {
+                        if let Err(err) = api.children(child).await {
+                            println!("{} => {:?}", child, err);
+                            errors += 1;
+                        }
+                    }
+                } else {
+                    println!("{} => {:?}", rating_key, res);
+                    errors += 1;
+                }
+                if let Err(err) = api.all_leaves(rating_key.as_str()).await {
+                    println!("{} => {:?}", rating_key, err);
+                    errors += 1;
+                }
+            }
+        }
+
+        assert_eq!(errors, 0);
+    }
+
     #[tokio::test]
     async fn test_directory_traversal() {
         let api = create_api();
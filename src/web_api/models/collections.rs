@@ -0,0 +1,107 @@
+use serde::{Deserialize, Serialize};
+
+use crate::web_api::models::{deserialize_number, deserialize_optional_number};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Collections {
+    pub size: u64,
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    #[serde(rename = "Metadata", default)]
+    pub collections: Vec<Collection>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Collection {
+    pub rating_key: String,
+    pub key: String,
+    pub guid: String,
+    #[serde(rename = "type")]
+    pub metadata_type: String,
+    pub title: String,
+    pub title_sort: Option<String>,
+    /// Type of the contained items, e.g. `movie`
+    pub subtype: Option<String>,
+    pub summary: String,
+    pub index: Option<u64>,
+    pub content_rating: Option<String>,
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_key: Option<String>,
+    pub thumb: Option<String>,
+    pub art: Option<String>,
+    #[serde(default)]
+    pub smart: bool,
+    /// Raw value of [`CollectionMode`]
+    pub collection_mode: Option<String>,
+    /// Raw value of [`CollectionSort`]
+    pub collection_sort: Option<String>,
+    #[serde(deserialize_with = "deserialize_number")]
+    pub child_count: u64,
+    #[serde(default, deserialize_with = "deserialize_optional_number")]
+    pub min_year: Option<u64>,
+    #[serde(default, deserialize_with = "deserialize_optional_number")]
+    pub max_year: Option<u64>,
+    pub added_at: u64,
+    pub updated_at: Option<u64>,
+}
+
+/// Whether the collection is shown in the library instead of its items
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollectionMode {
+    /// Use the setting of the section
+    Default,
+    Hide,
+    HideItems,
+    ShowItems,
+}
+
+impl CollectionMode {
+    pub fn id(&self) -> i8 {
+        match self {
+            CollectionMode::Default => -1,
+            CollectionMode::Hide => 0,
+            CollectionMode::HideItems => 1,
+            CollectionMode::ShowItems => 2,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CollectionSort {
+    ReleaseDate,
+    Alphabetical,
+    /// Order defined by moving the items
+    Custom,
+}
+
+impl CollectionSort {
+    pub fn id(&self) -> u8 {
+        match self {
+            CollectionSort::ReleaseDate => 0,
+            CollectionSort::Alphabetical => 1,
+            CollectionSort::Custom => 2,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Collection;
+
+    #[test]
+    fn test_counts_sent_as_strings() {
+        let json = r#"{"ratingKey":"42","key":"/library/collections/42/children","guid":"collection://abc","type":"collection","title":"Alien","subtype":"movie","summary":"","childCount":"4","minYear":"1979","maxYear":"1997","addedAt":1600000000}"#;
+
+        let collection: Collection = serde_json::from_str(json).unwrap();
+
+        assert_eq!(collection.child_count, 4);
+        assert_eq!(collection.min_year, Some(1979));
+        assert_eq!(collection.max_year, Some(1997));
+    }
+}
@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::web_api::models::Paging;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct History {
+    pub size: u64,
+    #[serde(flatten)]
+    pub paging: Option<Paging>,
+    #[serde(rename = "Metadata", default)]
+    pub entries: Vec<HistoryEntry>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct HistoryEntry {
+    pub history_key: String,
+    pub key: Option<String>,
+    /// Not set when the item has been removed from the library since
+    pub rating_key: Option<String>,
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<String>,
+    pub parent_key: Option<String>,
+    pub grandparent_key: Option<String>,
+    pub title: String,
+    pub parent_title: Option<String>,
+    pub grandparent_title: Option<String>,
+    #[serde(rename = "type")]
+    pub item_type: String,
+    pub thumb: Option<String>,
+    pub parent_thumb: Option<String>,
+    pub grandparent_thumb: Option<String>,
+    pub grandparent_art: Option<String>,
+    pub index: Option<u64>,
+    pub parent_index: Option<u64>,
+    pub originally_available_at: Option<String>,
+    pub viewed_at: u64,
+    #[serde(rename = "accountID")]
+    pub account_id: i64,
+    #[serde(rename = "deviceID")]
+    pub device_id: Option<i64>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::History;
+    use crate::web_api::models::MediaContainer;
+
+    #[test]
+    fn test_history() {
+        let json = r#"{"MediaContainer":{"size":2,"totalSize":120,"offset":20,"Metadata":[
+            {"historyKey":"/status/sessions/history/1","key":"/library/metadata/42","ratingKey":"42","librarySectionID":"2","parentKey":"/library/metadata/41","grandparentKey":"/library/metadata/40","title":"Pilot","parentTitle":"Season 1","grandparentTitle":"The Expanse","type":"episode","thumb":"/library/metadata/42/thumb/1","index":1,"parentIndex":1,"originallyAvailableAt":"2015-12-14","viewedAt":1600000000,"accountID":1,"deviceID":3},
+            {"historyKey":"/status/sessions/history/2","title":"Removed Movie","type":"movie","viewedAt":1500000000,"accountID":1}
+        ]}}"#;
+
+        let res: MediaContainer<History> = serde_json::from_str(json).unwrap();
+
+        let history = res.media_container;
+        let paging = history.paging.unwrap();
+        assert_eq!(paging.total_size, 120);
+        assert_eq!(paging.offset, 20);
+        assert_eq!(history.entries[0].rating_key.as_deref(), Some("42"));
+        assert_eq!(history.entries[1].rating_key, None);
+        assert_eq!(history.entries[1].device_id, None);
+    }
+}
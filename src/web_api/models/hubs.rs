@@ -0,0 +1,174 @@
+use serde::{Deserialize, Serialize};
+
+use crate::web_api::models::{deserialize_optional_number, Metadatum};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hubs {
+    pub size: u64,
+    pub identifier: Option<String>,
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    #[serde(rename = "Hub", default)]
+    pub hubs: Vec<Hub>,
+}
+
+/// Row of the home screen, e.g. On Deck or Recently Added Movies
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Hub {
+    pub title: String,
+    pub hub_identifier: String,
+    #[serde(rename = "type")]
+    pub hub_type: String,
+    /// Fetches all items of the hub when `more` is set
+    pub key: Option<String>,
+    /// Fetches the items contained in this response
+    pub hub_key: Option<String>,
+    pub context: Option<String>,
+    pub size: u64,
+    #[serde(default)]
+    pub more: bool,
+    pub style: Option<String>,
+    #[serde(default)]
+    pub promoted: bool,
+    #[serde(rename = "Metadata", default)]
+    pub metadata: Vec<Metadatum>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct SearchHubs {
+    pub size: u64,
+    #[serde(rename = "Hub", default)]
+    pub hubs: Vec<SearchHub>,
+}
+
+/// Search results of one type, e.g. movies or actors
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct SearchHub {
+    pub title: String,
+    pub hub_identifier: String,
+    #[serde(rename = "type")]
+    pub hub_type: String,
+    pub key: Option<String>,
+    pub hub_key: Option<String>,
+    pub context: Option<String>,
+    pub size: u64,
+    #[serde(default)]
+    pub more: bool,
+    pub style: Option<String>,
+    #[serde(rename = "Metadata", default)]
+    pub metadata: Vec<ScoredMetadatum>,
+    /// Tag results like actors, directors or genres
+    #[serde(rename = "Directory", default)]
+    pub directories: Vec<TagSearchResult>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScoredMetadatum {
+    /// Relevance between 0 and 1
+    #[serde(default, deserialize_with = "deserialize_optional_number")]
+    pub score: Option<f64>,
+    #[serde(flatten)]
+    pub metadatum: Metadatum,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct TagSearchResult {
+    pub id: i64,
+    pub tag: String,
+    pub tag_type: Option<i64>,
+    pub tag_key: Option<String>,
+    pub filter: Option<String>,
+    pub thumb: Option<String>,
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_type: Option<i64>,
+    #[serde(rename = "type")]
+    pub result_type: String,
+    #[serde(default, deserialize_with = "deserialize_optional_number")]
+    pub score: Option<f64>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Hubs, SearchHubs};
+    use crate::web_api::models::{MetadataContainer, Metadatum};
+
+    #[test]
+    fn test_search_hubs_carry_scores() {
+        let json = r#"{"size":2,"Hub":[
+            {"title":"Movies","type":"movie","hubIdentifier":"movie","context":"","size":1,"more":false,"style":"shelf","Metadata":[
+                {"score":"0.91049","ratingKey":"1","key":"/library/metadata/1","guid":"plex://movie/1","type":"movie","title":"Heat","summary":"","year":1995,"addedAt":1}
+            ]},
+            {"title":"Actors","type":"actor","hubIdentifier":"actor","context":"","size":1,"more":false,"style":"shelf","Directory":[
+                {"id":42,"tag":"Al Pacino","tagType":6,"filter":"actor=42","type":"tag","score":"0.5"}
+            ]}
+        ]}"#;
+
+        let results: SearchHubs = serde_json::from_str(json).unwrap();
+
+        let movie = &results.hubs[0].metadata[0];
+        assert_eq!(movie.score, Some(0.91049));
+        assert!(matches!(&movie.metadatum, Metadatum::Movie(movie) if movie.title == "Heat"));
+        assert_eq!(results.hubs[1].directories[0].tag, "Al Pacino");
+        assert_eq!(results.hubs[1].directories[0].score, Some(0.5));
+    }
+
+    #[test]
+    fn test_hubs_with_unknown_item_types() {
+        let json = r#"{"size":2,"Hub":[
+            {"title":"Recently Added Movies","type":"movie","hubIdentifier":"home.movies.recent","key":"/hubs/home/recentlyAdded?type=1","size":1,"more":true,"style":"shelf","promoted":true,"Metadata":[
+                {"ratingKey":"1","key":"/library/metadata/1","guid":"plex://movie/1","type":"movie","title":"Heat","summary":"","year":1995,"addedAt":1}
+            ]},
+            {"title":"Recent Playlists","type":"playlist","hubIdentifier":"home.playlists","key":"/playlists/all?playlistType=audio,video","size":1,"more":false,"style":"shelf","Metadata":[
+                {"ratingKey":"2","key":"/playlists/2/items","guid":"com.plexapp.agents.none://abc","type":"playlist","title":"Road Trip","playlistType":"audio","smart":false,"leafCount":12}
+            ]}
+        ]}"#;
+
+        let hubs: Hubs = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(&hubs.hubs[0].metadata[0], Metadatum::Movie(movie) if movie.title == "Heat"));
+        assert!(matches!(hubs.hubs[1].metadata[0], Metadatum::Unknown));
+    }
+
+    #[test]
+    fn test_search_hubs_with_collections() {
+        let json = r#"{"size":2,"Hub":[
+            {"title":"Movies","type":"movie","hubIdentifier":"movie","context":"","size":1,"more":false,"style":"shelf","Metadata":[
+                {"score":"0.8","ratingKey":"1","key":"/library/metadata/1","guid":"plex://movie/1","type":"movie","title":"Alien","summary":"","year":1979,"addedAt":1}
+            ]},
+            {"title":"Collections","type":"collection","hubIdentifier":"collection","context":"","size":1,"more":false,"style":"shelf","Metadata":[
+                {"score":"0.7","ratingKey":"2","key":"/library/collections/2/children","guid":"collection://abc","type":"collection","title":"Alien Collection","subtype":"movie","childCount":"4"}
+            ]}
+        ]}"#;
+
+        let results: SearchHubs = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(&results.hubs[0].metadata[0].metadatum, Metadatum::Movie(movie) if movie.title == "Alien"));
+        let collection = &results.hubs[1].metadata[0];
+        assert_eq!(collection.score, Some(0.7));
+        assert!(matches!(collection.metadatum, Metadatum::Unknown));
+    }
+
+    const ON_DECK_EPISODE: &str = r#"{"ratingKey":"42","key":"/library/metadata/42","parentRatingKey":"41","grandparentRatingKey":"40","guid":"plex://episode/1","parentGuid":"plex://season/1","grandparentGuid":"plex://show/1","type":"episode","title":"Pilot","grandparentKey":"/library/metadata/40","parentKey":"/library/metadata/41","grandparentTitle":"The Expanse","parentTitle":"Season 1","summary":"","index":1,"parentIndex":1,"duration":2640000,"addedAt":1600000000,"updatedAt":1600000001,"lastViewedAt":1600000002,"viewOffset":1234000}"#;
+
+    #[test]
+    fn test_continue_watching_view_offset() {
+        let hubs = format!(r#"{{"size":1,"Hub":[{{"title":"Continue Watching","type":"mixed","hubIdentifier":"home.continue","key":"/hubs/home/continueWatching","size":1,"more":false,"style":"hero","promoted":true,"Metadata":[{}]}}]}}"#, ON_DECK_EPISODE);
+        let on_deck = format!(r#"{{"size":1,"identifier":"com.plexapp.plugins.library","Metadata":[{}]}}"#, ON_DECK_EPISODE);
+
+        let hubs: Hubs = serde_json::from_str(&hubs).unwrap();
+        let on_deck: MetadataContainer = serde_json::from_str(&on_deck).unwrap();
+
+        assert!(matches!(&hubs.hubs[0].metadata[0], Metadatum::Episode(episode) if episode.view_offset == Some(1234000)));
+        assert!(matches!(&on_deck.metadata[0], Metadatum::Episode(episode) if episode.view_offset == Some(1234000)));
+    }
+}
@@ -1,5 +1,7 @@
 use serde::{Deserialize, Serialize};
 
+use crate::web_api::models::{AudioStream, Chapter, ClipMetadatum, Extras, Marker, SectionMeta, Stream, SubtitleStream, VideoStream};
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(test, serde(deny_unknown_fields))]
@@ -32,14 +34,17 @@ pub struct LibrarySection {
     pub directory: Vec<Directory>,
     #[serde(rename = "Metadata", default)]
     pub metadata: Vec<Metadatum>,
+    /// Only returned when requesting the section details
+    #[serde(rename = "Meta")]
+    pub meta: Option<SectionMeta>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 pub struct Paging {
-    total_size: u64,
-    offset: u64,
+    pub total_size: u64,
+    pub offset: u64,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -129,6 +134,7 @@ pub enum DirectoryType {
     Movie,
     Artist,
     Show,
+    Photo,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -141,13 +147,38 @@ pub enum Metadatum {
     Episode(EpisodeMetadatum),
     Movie(MovieMetadatum),
     Show(ShowMetadatum),
-    Folder
+    Season(SeasonMetadatum),
+    Clip(ClipMetadatum),
+    Folder,
+    /// Item types without a model, e.g. playlists, collections or photos
+    #[serde(other)]
+    Unknown,
+}
+
+impl Metadatum {
+    pub fn rating_key(&self) -> Option<&str> {
+        match self {
+            Metadatum::Artist(item) => Some(&item.rating_key),
+            Metadatum::Album(item) => Some(&item.rating_key),
+            Metadatum::Track(item) => Some(&item.rating_key),
+            Metadatum::Episode(item) => Some(&item.rating_key),
+            Metadatum::Movie(item) => Some(&item.rating_key),
+            Metadatum::Show(item) => Some(&item.rating_key),
+            Metadatum::Season(item) => Some(&item.rating_key),
+            Metadatum::Clip(item) => Some(&item.rating_key),
+            Metadatum::Folder | Metadatum::Unknown => None
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "camelCase")]
 pub struct ArtistMetadatum {
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_key: Option<String>,
     pub rating_key: String,
     pub key: String,
     pub guid: String,
@@ -179,6 +210,7 @@ pub struct AlbumMetadatum {
     pub library_section_title: Option<String>,
     #[serde(rename = "librarySectionUUID")]
     pub library_section_uuid: Option<String>,
+    pub library_section_key: Option<String>,
     pub rating_key: String,
     pub key: String,
     pub parent_key: String,
@@ -217,6 +249,10 @@ pub struct AlbumMetadatum {
 #[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "camelCase")]
 pub struct TrackMetadatum {
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_key: Option<String>,
     pub rating_key: String,
     pub key: String,
     pub parent_rating_key: String,
@@ -246,6 +282,7 @@ pub struct TrackMetadatum {
     #[serde(default)]
     pub view_count: u64,
     pub last_viewed_at: Option<u64>,
+    pub view_offset: Option<u64>,
     #[serde(rename = "Media", default)]
     pub media: Vec<Media>,
 }
@@ -254,6 +291,10 @@ pub struct TrackMetadatum {
 #[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "camelCase")]
 pub struct MovieMetadatum {
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_key: Option<String>,
     pub rating_key: String,
     pub key: String,
     pub guid: String,
@@ -293,12 +334,25 @@ pub struct MovieMetadatum {
     pub roles: Vec<Tag>,
     #[serde(default)]
     pub view_count: u64,
+    pub last_viewed_at: Option<u64>,
+    /// Resume position in milliseconds, set on partially watched items
+    pub view_offset: Option<u64>,
+    #[serde(rename = "Extras")]
+    pub extras: Option<Extras>,
+    #[serde(rename = "Chapter", default)]
+    pub chapters: Vec<Chapter>,
+    #[serde(rename = "Marker", default)]
+    pub markers: Vec<Marker>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "camelCase")]
 pub struct ShowMetadatum {
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_key: Option<String>,
     pub rating_key: String,
     pub key: String,
     pub guid: String,
@@ -330,12 +384,52 @@ pub struct ShowMetadatum {
     pub genres: Vec<Tag>,
     #[serde(rename = "Role", default)]
     pub roles: Vec<Tag>,
+    #[serde(rename = "Extras")]
+    pub extras: Option<Extras>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct SeasonMetadatum {
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_key: Option<String>,
+    pub rating_key: String,
+    pub key: String,
+    pub parent_rating_key: String,
+    pub guid: String,
+    pub parent_guid: String,
+    pub parent_studio: Option<String>,
+    pub title: String,
+    pub parent_key: String,
+    pub parent_title: String,
+    pub summary: String,
+    pub index: u64,
+    pub parent_index: Option<u64>,
+    pub parent_year: Option<u64>,
+    pub thumb: Option<String>,
+    pub art: Option<String>,
+    pub parent_thumb: Option<String>,
+    pub parent_theme: Option<String>,
+    pub leaf_count: u64,
+    pub viewed_leaf_count: u64,
+    pub added_at: u64,
+    pub updated_at: Option<u64>,
+    #[serde(default)]
+    pub view_count: u64,
+    pub last_viewed_at: Option<u64>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[cfg_attr(test, serde(deny_unknown_fields))]
 #[serde(rename_all = "camelCase")]
 pub struct EpisodeMetadatum {
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    pub library_section_key: Option<String>,
     pub rating_key: String,
     pub key: String,
     pub parent_rating_key: String,
@@ -371,10 +465,17 @@ pub struct EpisodeMetadatum {
     #[serde(default)]
     pub view_count: u64,
     pub last_viewed_at: Option<u64>,
+    pub view_offset: Option<u64>,
     #[serde(rename = "Media", default)]
     pub media: Vec<Media>,
     #[serde(rename = "Writer", default)]
     pub writers: Vec<Tag>,
+    #[serde(rename = "Extras")]
+    pub extras: Option<Extras>,
+    #[serde(rename = "Chapter", default)]
+    pub chapters: Vec<Chapter>,
+    #[serde(rename = "Marker", default)]
+    pub markers: Vec<Marker>,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -398,6 +499,7 @@ pub enum ViewGroup {
     Artist,
     Movie,
     Show,
+    Season,
     Track,
     Episode,
     Albums,
@@ -465,4 +567,56 @@ pub struct Part {
     #[serde(default)]
     pub optimized_for_streaming: bool,
     pub packet_length: Option<u64>,
+    #[serde(rename = "Stream", default)]
+    pub streams: Vec<Stream>,
+}
+
+impl Part {
+    pub fn video_streams(&self) -> impl Iterator<Item = &VideoStream> {
+        self.streams.iter().filter_map(|stream| match stream {
+            Stream::Video(stream) => Some(stream),
+            _ => None
+        })
+    }
+
+    pub fn audio_streams(&self) -> impl Iterator<Item = &AudioStream> {
+        self.streams.iter().filter_map(|stream| match stream {
+            Stream::Audio(stream) => Some(stream),
+            _ => None
+        })
+    }
+
+    pub fn subtitle_streams(&self) -> impl Iterator<Item = &SubtitleStream> {
+        self.streams.iter().filter_map(|stream| match stream {
+            Stream::Subtitle(stream) => Some(stream),
+            _ => None
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Metadatum;
+    use crate::web_api::models::{MediaContainer, MetadataContainer};
+
+    #[test]
+    fn test_show_children() {
+        let json = r#"{"MediaContainer":{"size":2,"identifier":"com.plexapp.plugins.library","librarySectionID":2,"librarySectionTitle":"TV Shows","librarySectionUUID":"abc","title1":"TV Shows","title2":"The Expanse","Metadata":[
+            {"ratingKey":"11","key":"/library/metadata/11/children","parentRatingKey":"10","guid":"plex://season/1","parentGuid":"plex://show/1","parentStudio":"Syfy","type":"season","title":"Season 1","parentKey":"/library/metadata/10","parentTitle":"The Expanse","summary":"","index":1,"parentIndex":1,"parentYear":2015,"thumb":"/library/metadata/11/thumb/1","art":"/library/metadata/10/art/1","parentThumb":"/library/metadata/10/thumb/1","parentTheme":"/library/metadata/10/theme/1","leafCount":10,"viewedLeafCount":10,"addedAt":1600000000,"updatedAt":1600000001,"viewCount":12,"lastViewedAt":1600000002},
+            {"ratingKey":"12","key":"/library/metadata/12/children","parentRatingKey":"10","guid":"plex://season/2","parentGuid":"plex://show/1","type":"season","title":"Season 2","parentKey":"/library/metadata/10","parentTitle":"The Expanse","summary":"The second season","index":2,"parentIndex":1,"leafCount":13,"viewedLeafCount":0,"addedAt":1600000000}
+        ]}}"#;
+
+        let res: MediaContainer<MetadataContainer> = serde_json::from_str(json).unwrap();
+
+        let seasons: Vec<_> = res.media_container.metadata.iter()
+            .filter_map(|item| match item {
+                Metadatum::Season(season) => Some(season),
+                _ => None
+            })
+            .collect();
+        assert_eq!(seasons.len(), 2);
+        assert_eq!(seasons[0].viewed_leaf_count, 10);
+        assert_eq!(seasons[1].leaf_count, 13);
+        assert_eq!(seasons[1].view_count, 0);
+    }
 }
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct MatchCandidates {
+    pub size: i64,
+    pub identifier: Option<String>,
+    pub media_tag_prefix: Option<String>,
+    pub media_tag_version: Option<i64>,
+    #[serde(rename = "SearchResult", default)]
+    pub candidates: Vec<MatchCandidate>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct MatchCandidate {
+    /// Agent guid, pass to [`PlexWebApi::apply_match`](crate::PlexWebApi::apply_match) to fix the match.
+    pub guid: String,
+    pub name: String,
+    /// Confidence of the agent between 0 and 100
+    pub score: i64,
+    pub year: Option<u64>,
+    pub thumb: Option<String>,
+    pub summary: Option<String>,
+    #[serde(rename = "type")]
+    pub metadata_type: Option<String>,
+    /// Whether the item is currently matched to this candidate
+    #[serde(default)]
+    pub matched: bool,
+    #[serde(default)]
+    pub lifespan_ended: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::MatchCandidates;
+
+    #[test]
+    fn test_candidates() {
+        let json = r#"{"size":2,"identifier":"com.plexapp.plugins.library","mediaTagPrefix":"/system/bundle/media/flags/","mediaTagVersion":1600000000,"SearchResult":[{"thumb":"https://image.tmdb.org/t/p/original/alien.jpg","guid":"plex://movie/5d7768253c3c2a001fbcab7d","name":"Alien","score":100,"year":1979,"type":"movie","matched":true,"lifespanEnded":false},{"guid":"plex://movie/5d776826999c64001ec2c6bb","name":"Aliens","score":84,"year":1986,"type":"movie","lifespanEnded":false}]}"#;
+
+        let candidates: MatchCandidates = serde_json::from_str(json).unwrap();
+
+        assert_eq!(candidates.candidates.len(), 2);
+        assert!(candidates.candidates[0].matched);
+        assert!(!candidates.candidates[1].matched);
+        assert_eq!(candidates.candidates[1].year, Some(1986));
+    }
+}
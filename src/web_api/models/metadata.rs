@@ -0,0 +1,166 @@
+use serde::{Deserialize, Serialize};
+
+use crate::web_api::models::{Media, Metadatum};
+
+/// Container returned by the metadata endpoints.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataContainer {
+    pub size: u64,
+    pub identifier: Option<String>,
+    #[serde(rename = "librarySectionID")]
+    pub library_section_id: Option<i64>,
+    pub library_section_title: Option<String>,
+    #[serde(rename = "librarySectionUUID")]
+    pub library_section_uuid: Option<String>,
+    #[serde(rename = "title1")]
+    pub title: Option<String>,
+    #[serde(rename = "title2")]
+    pub secondary: Option<String>,
+    #[serde(rename = "Metadata", default)]
+    pub metadata: Vec<Metadatum>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Extras {
+    pub size: u64,
+    #[serde(rename = "Metadata", default)]
+    pub metadata: Vec<Metadatum>,
+}
+
+/// Trailers, featurettes and other extras
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct ClipMetadatum {
+    pub rating_key: String,
+    pub key: String,
+    pub guid: String,
+    pub title: String,
+    pub summary: Option<String>,
+    pub subtype: Option<String>,
+    pub extra_type: Option<u64>,
+    pub index: Option<u64>,
+    pub year: Option<u64>,
+    pub thumb: Option<String>,
+    pub art: Option<String>,
+    pub duration: Option<u64>,
+    pub originally_available_at: Option<String>,
+    pub added_at: Option<u64>,
+    pub view_offset: Option<u64>,
+    pub primary_guid: Option<String>,
+    #[serde(rename = "Media", default)]
+    pub media: Vec<Media>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct Chapter {
+    pub id: u64,
+    pub filter: Option<String>,
+    pub index: u64,
+    pub tag: Option<String>,
+    pub start_time_offset: u64,
+    pub end_time_offset: u64,
+    pub thumb: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+#[serde(rename_all = "camelCase")]
+pub struct Marker {
+    pub id: u64,
+    #[serde(rename = "type")]
+    pub marker_type: MarkerType,
+    pub start_time_offset: u64,
+    pub end_time_offset: u64,
+    /// Marks the last credits marker of an item
+    #[serde(rename = "final", default)]
+    pub is_final: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarkerType {
+    Intro,
+    Credits,
+    Commercial,
+    #[serde(other)]
+    Unknown,
+}
+
+/// Numeric item type used by the `type` query parameter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MetadataType {
+    Movie,
+    Show,
+    Season,
+    Episode,
+    Trailer,
+    Artist,
+    Album,
+    Track,
+    Clip,
+    Photo,
+    PhotoAlbum,
+    Playlist,
+    Collection,
+}
+
+impl MetadataType {
+    pub fn id(&self) -> u8 {
+        match self {
+            MetadataType::Movie => 1,
+            MetadataType::Show => 2,
+            MetadataType::Season => 3,
+            MetadataType::Episode => 4,
+            MetadataType::Trailer => 5,
+            MetadataType::Artist => 8,
+            MetadataType::Album => 9,
+            MetadataType::Track => 10,
+            MetadataType::Clip => 12,
+            MetadataType::Photo => 13,
+            MetadataType::PhotoAlbum => 14,
+            MetadataType::Playlist => 15,
+            MetadataType::Collection => 18,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{MarkerType, MetadataContainer};
+    use crate::web_api::models::Metadatum;
+
+    #[test]
+    fn test_movie_details() {
+        let json = r#"{"size":1,"identifier":"com.plexapp.plugins.library","librarySectionID":1,"librarySectionTitle":"Movies","librarySectionUUID":"abc","Metadata":[
+            {"ratingKey":"1","key":"/library/metadata/1","guid":"plex://movie/1","type":"movie","title":"Alien","summary":"","year":1979,"addedAt":1,"librarySectionID":1,"librarySectionTitle":"Movies","librarySectionKey":"/library/sections/1",
+                "Extras":{"size":1,"Metadata":[
+                    {"ratingKey":"2","key":"/library/metadata/2","guid":"plex://movie/1/trailer","type":"clip","title":"Trailer","subtype":"trailer","extraType":1,"index":1,"duration":120000,"addedAt":1,"primaryGuid":"plex://movie/1"}
+                ]},
+                "Chapter":[
+                    {"id":1,"filter":"thumb=1","index":1,"tag":"Opening","startTimeOffset":0,"endTimeOffset":300000,"thumb":"/library/media/1/chapterImages/1"}
+                ],
+                "Marker":[
+                    {"id":3,"type":"credits","startTimeOffset":6500000,"endTimeOffset":7000000,"final":true},
+                    {"id":4,"type":"bookmark","startTimeOffset":1000,"endTimeOffset":2000}
+                ]}
+        ]}"#;
+
+        let container: MetadataContainer = serde_json::from_str(json).unwrap();
+
+        let movie = match &container.metadata[0] {
+            Metadatum::Movie(movie) => movie,
+            item => panic!("expected movie, got {:?}", item)
+        };
+        let extras = movie.extras.as_ref().unwrap();
+        assert!(matches!(&extras.metadata[0], Metadatum::Clip(clip) if clip.subtype.as_deref() == Some("trailer")));
+        assert_eq!(movie.chapters[0].end_time_offset, 300000);
+        assert_eq!(movie.markers[0].marker_type, MarkerType::Credits);
+        assert!(movie.markers[0].is_final);
+        assert_eq!(movie.markers[1].marker_type, MarkerType::Unknown);
+    }
+}
@@ -1,18 +1,76 @@
-use serde::{Deserialize, Serialize};
+use std::fmt::Display;
+use std::str::FromStr;
 
+use serde::{Deserialize, Deserializer, Serialize};
+
+pub use collections::*;
+pub use history::*;
+pub use hubs::*;
 pub use library_section::*;
 pub use library_sections::*;
+pub use matches::*;
+pub use metadata::*;
+pub use notifications::*;
+pub use play_queues::*;
+pub use playlists::*;
 pub use search_results::*;
+pub use section_meta::*;
 pub use server_information::*;
+pub use sessions::*;
+pub use streams::*;
+pub use transcode_session::*;
 
+pub(crate) mod collections;
+pub(crate) mod history;
+pub(crate) mod hubs;
 pub(crate) mod library_section;
 pub(crate) mod library_sections;
+pub(crate) mod matches;
+pub(crate) mod metadata;
+pub(crate) mod notifications;
+pub(crate) mod play_queues;
+pub(crate) mod playlists;
 pub(crate) mod search_results;
+pub(crate) mod section_meta;
 pub(crate) mod server_information;
+pub(crate) mod sessions;
+pub(crate) mod streams;
+pub(crate) mod transcode_session;
 
+/// Response envelope of the api.
+///
+/// The attributes of the inner container differ between endpoints, the models only map the common ones.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct MediaContainer<T> {
     #[serde(rename = "MediaContainer")]
     pub media_container: T,
 }
 
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrText<T> {
+    Number(T),
+    Text(String),
+}
+
+impl<T: FromStr> NumberOrText<T> where T::Err: Display {
+    fn into_number<E: serde::de::Error>(self) -> Result<T, E> {
+        match self {
+            NumberOrText::Number(number) => Ok(number),
+            NumberOrText::Text(number) => number.parse().map_err(E::custom),
+        }
+    }
+}
+
+/// The server sends some numbers as strings, e.g. scores or child counts
+pub(crate) fn deserialize_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where D: Deserializer<'de>, T: Deserialize<'de> + FromStr, T::Err: Display {
+    NumberOrText::deserialize(deserializer)?.into_number()
+}
+
+pub(crate) fn deserialize_optional_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+    where D: Deserializer<'de>, T: Deserialize<'de> + FromStr, T::Err: Display {
+    Option::<NumberOrText<T>>::deserialize(deserializer)?
+        .map(NumberOrText::into_number)
+        .transpose()
+}
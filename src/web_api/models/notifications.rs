@@ -0,0 +1,228 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::web_api::models::TranscodeSession;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationContainer {
+    #[serde(rename = "NotificationContainer")]
+    pub notification: Notification,
+}
+
+/// Event received from the notification websocket, discriminated by its `type`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Notification {
+    #[serde(rename = "playing")]
+    Playing {
+        #[serde(rename = "PlaySessionStateNotification", default)]
+        sessions: Vec<PlaySessionStateNotification>,
+    },
+    #[serde(rename = "timeline")]
+    Timeline {
+        #[serde(rename = "TimelineEntry", default)]
+        entries: Vec<TimelineEntry>,
+    },
+    #[serde(rename = "activity")]
+    Activity {
+        #[serde(rename = "ActivityNotification", default)]
+        activities: Vec<ActivityNotification>,
+    },
+    #[serde(rename = "status")]
+    Status {
+        #[serde(rename = "StatusNotification", default)]
+        statuses: Vec<StatusNotification>,
+    },
+    #[serde(rename = "reachability")]
+    Reachability {
+        #[serde(rename = "ReachabilityNotification", default)]
+        reachability: Vec<ReachabilityNotification>,
+    },
+    #[serde(rename = "transcodeSession.start")]
+    TranscodeSessionStart {
+        #[serde(rename = "TranscodeSession", default)]
+        sessions: Vec<TranscodeSession>,
+    },
+    #[serde(rename = "transcodeSession.update")]
+    TranscodeSessionUpdate {
+        #[serde(rename = "TranscodeSession", default)]
+        sessions: Vec<TranscodeSession>,
+    },
+    #[serde(rename = "transcodeSession.end")]
+    TranscodeSessionEnd {
+        #[serde(rename = "TranscodeSession", default)]
+        sessions: Vec<TranscodeSession>,
+    },
+    /// Notification types without a typed model, e.g. `backgroundProcessingQueue`
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaybackState {
+    Playing,
+    Paused,
+    Buffering,
+    Stopped,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct PlaySessionStateNotification {
+    pub session_key: String,
+    pub client_identifier: String,
+    pub guid: String,
+    pub rating_key: String,
+    pub url: String,
+    pub key: String,
+    pub view_offset: u64,
+    #[serde(rename = "playQueueID")]
+    pub play_queue_id: Option<u64>,
+    #[serde(rename = "playQueueItemID")]
+    pub play_queue_item_id: Option<u64>,
+    pub state: PlaybackState,
+    pub transcode_session: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct TimelineEntry {
+    pub identifier: String,
+    #[serde(rename = "sectionID")]
+    pub section_id: String,
+    #[serde(rename = "itemID")]
+    pub item_id: String,
+    #[serde(rename = "parentItemID")]
+    pub parent_item_id: Option<String>,
+    #[serde(rename = "rootItemID")]
+    pub root_item_id: Option<String>,
+    #[serde(rename = "type")]
+    pub item_type: i64,
+    pub title: String,
+    /// Processing state of the item, `9` means it has been deleted
+    pub state: i64,
+    pub metadata_state: Option<String>,
+    pub media_state: Option<String>,
+    pub queue_size: Option<u64>,
+    pub updated_at: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct ActivityNotification {
+    /// `started`, `updated` or `ended`
+    pub event: String,
+    pub uuid: String,
+    #[serde(rename = "Activity")]
+    pub activity: Activity,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Activity {
+    pub uuid: String,
+    /// e.g. `library.update.section` or `media.generate.bif`
+    #[serde(rename = "type")]
+    pub activity_type: String,
+    #[serde(default)]
+    pub cancellable: bool,
+    #[serde(rename = "userID")]
+    pub user_id: i64,
+    pub title: String,
+    pub subtitle: Option<String>,
+    pub progress: i64,
+    #[serde(rename = "Context")]
+    pub context: Option<Value>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct StatusNotification {
+    pub title: String,
+    pub description: String,
+    pub notification_name: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct ReachabilityNotification {
+    pub reachability: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Notification, NotificationContainer};
+
+    fn decode(json: &str) -> Notification {
+        serde_json::from_str::<NotificationContainer>(json).unwrap().notification
+    }
+
+    #[test]
+    fn test_timeline() {
+        let json = r#"{"NotificationContainer":{"type":"timeline","size":2,"TimelineEntry":[
+            {"identifier":"com.plexapp.plugins.library","sectionID":"1","itemID":"1337","type":1,"title":"Heat","state":0,"metadataState":"created","updatedAt":1600000000},
+            {"identifier":"com.plexapp.plugins.library","sectionID":"2","itemID":"42","parentItemID":"41","rootItemID":"40","type":4,"title":"Pilot","state":5,"mediaState":"analyzing","queueSize":3,"updatedAt":1600000001}
+        ]}}"#;
+
+        match decode(json) {
+            Notification::Timeline { entries } => {
+                assert_eq!(entries.len(), 2);
+                assert_eq!(entries[0].item_id, "1337");
+                assert_eq!(entries[1].root_item_id.as_deref(), Some("40"));
+                assert_eq!(entries[1].queue_size, Some(3));
+            }
+            notification => panic!("expected timeline notification, got {:?}", notification)
+        }
+    }
+
+    #[test]
+    fn test_activity() {
+        let json = r#"{"NotificationContainer":{"type":"activity","size":1,"ActivityNotification":[
+            {"event":"updated","uuid":"abc","Activity":{"uuid":"abc","type":"library.update.section","cancellable":false,"userID":1,"title":"Scanning Movies","subtitle":"Alien","progress":42,"Context":{"librarySectionID":"1"}}}
+        ]}}"#;
+
+        match decode(json) {
+            Notification::Activity { activities } => {
+                assert_eq!(activities[0].event, "updated");
+                assert_eq!(activities[0].activity.activity_type, "library.update.section");
+                assert_eq!(activities[0].activity.progress, 42);
+                assert_eq!(activities[0].activity.context.as_ref().unwrap()["librarySectionID"], "1");
+            }
+            notification => panic!("expected activity notification, got {:?}", notification)
+        }
+    }
+
+    #[test]
+    fn test_transcode_sessions() {
+        let update = r#"{"NotificationContainer":{"type":"transcodeSession.update","size":1,"TranscodeSession":[
+            {"key":"/transcode/sessions/abc","throttled":false,"complete":false,"progress":12.5,"size":-22,"speed":3.2,"error":false,"duration":7200000,"remaining":120,"context":"streaming","sourceVideoCodec":"hevc","sourceAudioCodec":"dca","videoDecision":"transcode","audioDecision":"transcode","protocol":"dash","container":"mp4","videoCodec":"h264","audioCodec":"aac","audioChannels":2,"width":1920,"height":1080,"transcodeHwRequested":true,"transcodeHwFullPipeline":false,"timeStamp":1600000000.5,"maxOffsetAvailable":900.5,"minOffsetAvailable":0.0}
+        ]}}"#;
+        let end = r#"{"NotificationContainer":{"type":"transcodeSession.end","size":1,"TranscodeSession":[{"key":"/transcode/sessions/abc"}]}}"#;
+
+        match decode(update) {
+            Notification::TranscodeSessionUpdate { sessions } => {
+                assert_eq!(sessions[0].progress, Some(12.5));
+                assert_eq!(sessions[0].video_decision.as_deref(), Some("transcode"));
+            }
+            notification => panic!("expected transcode update, got {:?}", notification)
+        }
+        match decode(end) {
+            Notification::TranscodeSessionEnd { sessions } => assert_eq!(sessions[0].key, "/transcode/sessions/abc"),
+            notification => panic!("expected transcode end, got {:?}", notification)
+        }
+    }
+
+    #[test]
+    fn test_status_and_reachability() {
+        let status = r#"{"NotificationContainer":{"type":"status","size":1,"StatusNotification":[{"title":"Library scan complete","description":"","notificationName":"LIBRARY_UPDATE"}]}}"#;
+        let reachability = r#"{"NotificationContainer":{"type":"reachability","size":1,"ReachabilityNotification":[{"reachability":true}]}}"#;
+
+        assert!(matches!(decode(status), Notification::Status { statuses } if statuses[0].notification_name == "LIBRARY_UPDATE"));
+        assert!(matches!(decode(reachability), Notification::Reachability { reachability } if reachability[0].reachability));
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use crate::web_api::models::Metadatum;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayQueue {
+    pub size: u64,
+    #[serde(rename = "playQueueID")]
+    pub play_queue_id: u64,
+    #[serde(rename = "playQueueSelectedItemID")]
+    pub selected_item_id: u64,
+    #[serde(rename = "playQueueSelectedItemOffset")]
+    pub selected_item_offset: u64,
+    #[serde(rename = "playQueueSelectedMetadataItemID")]
+    pub selected_metadata_item_id: String,
+    #[serde(rename = "playQueueShuffled")]
+    pub shuffled: bool,
+    #[serde(rename = "playQueueSourceURI")]
+    pub source_uri: String,
+    #[serde(rename = "playQueueTotalCount")]
+    pub total_count: u64,
+    /// Increases with every change of the queue
+    #[serde(rename = "playQueueVersion")]
+    pub version: u64,
+    #[serde(rename = "playQueueLastAddedItemID")]
+    pub last_added_item_id: Option<String>,
+    #[serde(rename = "Metadata", default)]
+    pub items: Vec<PlayQueueItem>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlayQueueItem {
+    /// Identifies the entry in the queue, required to move or remove it
+    #[serde(rename = "playQueueItemID")]
+    pub play_queue_item_id: u64,
+    #[serde(flatten)]
+    pub item: Metadatum,
+}
+
+#[cfg(test)]
+mod test {
+    use super::PlayQueue;
+    use crate::web_api::models::Metadatum;
+
+    #[test]
+    fn test_play_queue() {
+        let json = r#"{"size":2,"playQueueID":7,"playQueueSelectedItemID":101,"playQueueSelectedItemOffset":0,"playQueueSelectedMetadataItemID":"1","playQueueShuffled":false,"playQueueSourceURI":"server://abc/com.plexapp.plugins.library/library/metadata/1,2","playQueueTotalCount":2,"playQueueVersion":1,"Metadata":[
+            {"playQueueItemID":101,"ratingKey":"1","key":"/library/metadata/1","guid":"plex://movie/1","type":"movie","title":"Heat","summary":"","year":1995,"addedAt":1},
+            {"playQueueItemID":102,"ratingKey":"2","key":"/library/metadata/2","guid":"plex://movie/2","type":"movie","title":"Alien","summary":"","year":1979,"addedAt":1}
+        ]}"#;
+
+        let queue: PlayQueue = serde_json::from_str(json).unwrap();
+
+        assert_eq!(queue.play_queue_id, 7);
+        assert_eq!(queue.selected_item_id, 101);
+        assert_eq!(queue.items[1].play_queue_item_id, 102);
+        assert!(matches!(&queue.items[1].item, Metadatum::Movie(movie) if movie.title == "Alien"));
+    }
+}
@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+
+use crate::web_api::models::Metadatum;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Playlists {
+    pub size: u64,
+    #[serde(rename = "Metadata", default)]
+    pub playlists: Vec<Playlist>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PlaylistType {
+    Audio,
+    Video,
+    Photo,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Playlist {
+    pub rating_key: String,
+    pub key: String,
+    pub guid: String,
+    #[serde(rename = "type")]
+    pub metadata_type: String,
+    pub title: String,
+    pub title_sort: Option<String>,
+    #[serde(default)]
+    pub summary: String,
+    #[serde(default)]
+    pub smart: bool,
+    pub playlist_type: PlaylistType,
+    /// Uri of the section query for smart playlists
+    pub content: Option<String>,
+    pub composite: Option<String>,
+    pub icon: Option<String>,
+    #[serde(default)]
+    pub allow_sync: bool,
+    #[serde(default)]
+    pub view_count: u64,
+    pub last_viewed_at: Option<u64>,
+    pub duration: Option<u64>,
+    pub leaf_count: u64,
+    pub added_at: u64,
+    pub updated_at: Option<u64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlaylistItems {
+    pub size: u64,
+    pub title: Option<String>,
+    pub playlist_type: Option<PlaylistType>,
+    #[serde(default)]
+    pub smart: bool,
+    pub duration: Option<u64>,
+    pub leaf_count: Option<u64>,
+    #[serde(rename = "Metadata", default)]
+    pub items: Vec<PlaylistItem>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct PlaylistItem {
+    /// Identifies the entry in the playlist, required to move or remove it
+    #[serde(rename = "playlistItemID")]
+    pub playlist_item_id: u64,
+    #[serde(flatten)]
+    pub item: Metadatum,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{PlaylistItems, PlaylistType, Playlists};
+    use crate::web_api::models::{MediaContainer, Metadatum};
+
+    #[test]
+    fn test_playlists() {
+        let json = r#"{"MediaContainer":{"size":2,"Metadata":[
+            {"ratingKey":"5","key":"/playlists/5/items","guid":"com.plexapp.agents.none://abc","type":"playlist","title":"Road Trip","summary":"","smart":false,"playlistType":"audio","composite":"/playlists/5/composite/1","viewCount":2,"lastViewedAt":1600000002,"duration":3600000,"leafCount":12,"addedAt":1600000000,"updatedAt":1600000001},
+            {"ratingKey":"6","key":"/playlists/6/items","guid":"com.plexapp.agents.none://def","type":"playlist","title":"Unwatched","smart":true,"playlistType":"video","content":"library://abc/directory/%2Flibrary%2Fsections%2F1%2Fall%3Funwatched%3D1","icon":"playlist://image.smart","leafCount":0,"addedAt":1600000000}
+        ]}}"#;
+
+        let res: MediaContainer<Playlists> = serde_json::from_str(json).unwrap();
+
+        let playlists = res.media_container.playlists;
+        assert_eq!(playlists[0].playlist_type, PlaylistType::Audio);
+        assert_eq!(playlists[0].leaf_count, 12);
+        assert!(playlists[1].smart);
+        assert_eq!(playlists[1].summary, "");
+    }
+
+    #[test]
+    fn test_playlist_items() {
+        let json = r#"{"MediaContainer":{"size":2,"composite":"/playlists/6/composite/1","duration":13000000,"leafCount":2,"playlistType":"video","ratingKey":"6","smart":false,"title":"Movie Night","Metadata":[
+            {"playlistItemID":101,"ratingKey":"1","key":"/library/metadata/1","guid":"plex://movie/1","type":"movie","title":"Heat","summary":"","year":1995,"addedAt":1},
+            {"playlistItemID":102,"ratingKey":"2","key":"/library/metadata/2","guid":"plex://movie/2","type":"movie","title":"Alien","summary":"","year":1979,"addedAt":1}
+        ]}}"#;
+
+        let res: MediaContainer<PlaylistItems> = serde_json::from_str(json).unwrap();
+
+        let playlist = res.media_container;
+        assert_eq!(playlist.title.as_deref(), Some("Movie Night"));
+        assert_eq!(playlist.items[1].playlist_item_id, 102);
+        assert!(matches!(&playlist.items[1].item, Metadatum::Movie(movie) if movie.title == "Alien"));
+    }
+}
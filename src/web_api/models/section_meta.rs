@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+/// Filters or sorts advertised by a section.
+///
+/// The container attributes match the ones of the section, only the definitions are mapped.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de>"))]
+pub struct SectionDefinitions<T> {
+    pub size: u64,
+    #[serde(rename = "Directory", default)]
+    pub definitions: Vec<T>,
+}
+
+impl SectionDefinitions<FilterDefinition> {
+    /// Names accepted by [`SectionQuery::validate`](crate::web_api::SectionQuery::validate)
+    pub fn filter_names(&self) -> impl Iterator<Item = &str> {
+        self.definitions.iter().map(|filter| filter.filter.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct FilterDefinition {
+    pub filter: String,
+    /// `string`, `integer`, `boolean` or `tag`
+    pub filter_type: String,
+    /// Lists the values available for this filter
+    pub key: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub definition_type: String,
+    #[serde(default)]
+    pub advanced: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct SortDefinition {
+    /// Set on the sort the section uses by default, contains the direction
+    pub default: Option<String>,
+    pub default_direction: Option<String>,
+    pub desc_key: Option<String>,
+    pub first_character_key: Option<String>,
+    pub key: String,
+    pub title: String,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct SectionMeta {
+    #[serde(rename = "Type", default)]
+    pub types: Vec<MetaType>,
+    #[serde(rename = "FieldType", default)]
+    pub field_types: Vec<FieldType>,
+}
+
+/// Filters, sorts and fields available for one item type of a section
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct MetaType {
+    pub key: String,
+    #[serde(rename = "type")]
+    pub metadata_type: String,
+    pub title: String,
+    #[serde(default)]
+    pub active: bool,
+    #[serde(rename = "Filter", default)]
+    pub filters: Vec<FilterDefinition>,
+    #[serde(rename = "Sort", default)]
+    pub sorts: Vec<SortDefinition>,
+    #[serde(rename = "Field", default)]
+    pub fields: Vec<FieldDefinition>,
+}
+
+impl MetaType {
+    pub fn filter_names(&self) -> impl Iterator<Item = &str> {
+        self.filters.iter().map(|filter| filter.filter.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct FieldDefinition {
+    pub key: String,
+    pub title: String,
+    #[serde(rename = "type")]
+    pub field_type: String,
+    pub sub_type: Option<String>,
+}
+
+/// Operators supported by the fields of a type, e.g. `=` or `>>=`
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct FieldType {
+    #[serde(rename = "type")]
+    pub field_type: String,
+    #[serde(rename = "Operator", default)]
+    pub operators: Vec<FieldOperator>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct FieldOperator {
+    pub key: String,
+    pub title: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FilterDefinition, SectionDefinitions, SortDefinition};
+    use crate::web_api::models::{LibrarySection, MediaContainer};
+
+    #[test]
+    fn test_filters() {
+        let json = r#"{"MediaContainer":{"size":2,"allowSync":false,"content":"secondary","identifier":"com.plexapp.plugins.library","Directory":[
+            {"filter":"genre","filterType":"string","key":"/library/sections/1/genre","title":"Genre","type":"filter"},
+            {"filter":"unwatched","filterType":"boolean","key":"/library/sections/1/unwatched","title":"Unwatched","type":"filter","advanced":true}
+        ]}}"#;
+
+        let res: MediaContainer<SectionDefinitions<FilterDefinition>> = serde_json::from_str(json).unwrap();
+
+        let filters = res.media_container;
+        assert_eq!(filters.filter_names().collect::<Vec<_>>(), vec!["genre", "unwatched"]);
+        assert!(filters.definitions[1].advanced);
+    }
+
+    #[test]
+    fn test_sorts() {
+        let json = r#"{"MediaContainer":{"size":2,"allowSync":false,"identifier":"com.plexapp.plugins.library","Directory":[
+            {"default":"asc","defaultDirection":"asc","descKey":"titleSort:desc","firstCharacterKey":"/library/sections/1/firstCharacter","key":"titleSort","title":"Title"},
+            {"defaultDirection":"desc","descKey":"addedAt:desc","key":"addedAt","title":"Date Added"}
+        ]}}"#;
+
+        let res: MediaContainer<SectionDefinitions<SortDefinition>> = serde_json::from_str(json).unwrap();
+
+        let sorts = res.media_container.definitions;
+        assert_eq!(sorts[0].default.as_deref(), Some("asc"));
+        assert_eq!(sorts[1].desc_key.as_deref(), Some("addedAt:desc"));
+        assert_eq!(sorts[1].default, None);
+    }
+
+    #[test]
+    fn test_section_details() {
+        let json = r#"{"MediaContainer":{"size":1,"allowSync":true,"content":"secondary","identifier":"com.plexapp.plugins.library","librarySectionID":1,"librarySectionTitle":"Movies","librarySectionUUID":"abc","mediaTagPrefix":"/system/bundle/media/flags/","mediaTagVersion":1600000000,"title1":"Movies","viewGroup":"secondary","viewMode":65592,
+            "Directory":[{"key":"all","title":"All Movies"}],
+            "Meta":{
+                "Type":[{"key":"/library/sections/1/all?type=1","type":"movie","title":"Movies","active":true,
+                    "Filter":[{"filter":"genre","filterType":"string","key":"/library/sections/1/genre","title":"Genre","type":"filter"}],
+                    "Sort":[{"defaultDirection":"desc","descKey":"addedAt:desc","key":"addedAt","title":"Date Added"}],
+                    "Field":[{"key":"title","title":"Title","type":"string"},{"key":"label","title":"Label","type":"tag","subType":"label"}]
+                }],
+                "FieldType":[{"type":"tag","Operator":[{"key":"=","title":"is"},{"key":"!=","title":"is not"}]}]
+            }
+        }}"#;
+
+        let res: MediaContainer<LibrarySection> = serde_json::from_str(json).unwrap();
+
+        let meta = res.media_container.meta.unwrap();
+        let movie = &meta.types[0];
+        assert!(movie.active);
+        assert_eq!(movie.filter_names().collect::<Vec<_>>(), vec!["genre"]);
+        assert_eq!(movie.sorts[0].key, "addedAt");
+        assert_eq!(movie.fields[1].sub_type.as_deref(), Some("label"));
+        assert_eq!(meta.field_types[0].operators[1].key, "!=");
+    }
+}
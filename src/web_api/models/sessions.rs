@@ -0,0 +1,115 @@
+use serde::{Deserialize, Serialize};
+
+use crate::web_api::models::{ClipMetadatum, EpisodeMetadatum, MovieMetadatum, PlaybackState, TrackMetadatum, TranscodeSession};
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Sessions {
+    pub size: u64,
+    #[serde(rename = "Metadata", default)]
+    pub sessions: Vec<SessionEntry>,
+}
+
+/// Item currently being played, together with the user and player playing it.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionEntry {
+    #[serde(flatten)]
+    pub item: SessionItem,
+    pub session_key: String,
+    pub view_offset: Option<u64>,
+    #[serde(rename = "User")]
+    pub user: User,
+    #[serde(rename = "Player")]
+    pub player: Player,
+    #[serde(rename = "Session")]
+    pub session: Option<Session>,
+    /// Only set when the server transcodes the item
+    #[serde(rename = "TranscodeSession")]
+    pub transcode_session: Option<TranscodeSession>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum SessionItem {
+    Movie(MovieMetadatum),
+    Episode(EpisodeMetadatum),
+    Track(TrackMetadatum),
+    Clip(ClipMetadatum),
+    /// Item types without a model, e.g. photos
+    #[serde(other)]
+    Unknown,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct User {
+    pub id: String,
+    pub title: String,
+    pub thumb: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Player {
+    pub address: String,
+    pub device: Option<String>,
+    pub machine_identifier: String,
+    pub model: Option<String>,
+    pub platform: String,
+    pub platform_version: Option<String>,
+    pub product: String,
+    pub profile: Option<String>,
+    pub remote_public_address: Option<String>,
+    pub state: PlaybackState,
+    pub title: String,
+    pub vendor: Option<String>,
+    pub version: Option<String>,
+    #[serde(default)]
+    pub local: bool,
+    #[serde(default)]
+    pub relayed: bool,
+    #[serde(default)]
+    pub secure: bool,
+    #[serde(rename = "userID")]
+    pub user_id: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(test, serde(deny_unknown_fields))]
+pub struct Session {
+    pub id: String,
+    /// Reserved bandwidth in kbps
+    pub bandwidth: Option<u64>,
+    /// `lan` or `wan`
+    pub location: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SessionItem, Sessions};
+
+    #[test]
+    fn test_unknown_item_types() {
+        let json = r#"{"size":3,"Metadata":[
+            {"sessionKey":"1","ratingKey":"1","key":"/library/metadata/1","guid":"plex://movie/1","type":"movie","title":"Heat","summary":"","year":1995,"addedAt":1,"viewOffset":1000,
+                "User":{"id":"1","title":"admin"},
+                "Player":{"address":"10.0.0.2","machineIdentifier":"abc","platform":"Chrome","product":"Plex Web","state":"playing","title":"Chrome"}},
+            {"sessionKey":"2","ratingKey":"2","key":"/library/metadata/2","guid":"plex://movie/1/trailer","type":"clip","title":"Heat Trailer","subtype":"trailer",
+                "User":{"id":"1","title":"admin"},
+                "Player":{"address":"10.0.0.3","machineIdentifier":"def","platform":"Android","product":"Plex for Android","state":"paused","title":"Phone"}},
+            {"sessionKey":"3","ratingKey":"3","key":"/library/metadata/3","type":"photo","title":"Beach",
+                "User":{"id":"2","title":"guest"},
+                "Player":{"address":"10.0.0.4","machineIdentifier":"ghi","platform":"iOS","product":"Plex for iOS","state":"playing","title":"Tablet"}}
+        ]}"#;
+
+        let sessions: Sessions = serde_json::from_str(json).unwrap();
+
+        assert_eq!(sessions.sessions.len(), 3);
+        assert!(matches!(&sessions.sessions[0].item, SessionItem::Movie(movie) if movie.title == "Heat"));
+        assert!(matches!(&sessions.sessions[1].item, SessionItem::Clip(clip) if clip.subtype.as_deref() == Some("trailer")));
+        assert!(matches!(sessions.sessions[2].item, SessionItem::Unknown));
+        assert_eq!(sessions.sessions[2].user.title, "guest");
+    }
+}
@@ -0,0 +1,148 @@
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+
+/// Elementary stream of a [`Part`](crate::web_api::models::Part), discriminated by `streamType`.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum Stream {
+    Video(VideoStream),
+    Audio(AudioStream),
+    Subtitle(SubtitleStream),
+    /// Stream types without a typed model, e.g. lyrics
+    Other(Value),
+}
+
+impl<'de> Deserialize<'de> for Stream {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let stream = match value.get("streamType").and_then(Value::as_u64) {
+            Some(1) => Stream::Video(serde_json::from_value(value).map_err(D::Error::custom)?),
+            Some(2) => Stream::Audio(serde_json::from_value(value).map_err(D::Error::custom)?),
+            Some(3) => Stream::Subtitle(serde_json::from_value(value).map_err(D::Error::custom)?),
+            _ => Stream::Other(value),
+        };
+
+        Ok(stream)
+    }
+}
+
+impl Stream {
+    pub fn id(&self) -> Option<u64> {
+        match self {
+            Stream::Video(stream) => Some(stream.id),
+            Stream::Audio(stream) => Some(stream.id),
+            Stream::Subtitle(stream) => Some(stream.id),
+            Stream::Other(value) => value.get("id").and_then(Value::as_u64),
+        }
+    }
+}
+
+// Streams carry a lot of codec specific attributes, only the commonly used ones are mapped.
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VideoStream {
+    pub id: u64,
+    pub stream_type: u64,
+    pub index: Option<u64>,
+    pub codec: String,
+    #[serde(default)]
+    pub default: bool,
+    #[serde(default)]
+    pub selected: bool,
+    pub bitrate: Option<u64>,
+    pub width: Option<u64>,
+    pub height: Option<u64>,
+    pub frame_rate: Option<f64>,
+    pub profile: Option<String>,
+    pub level: Option<u64>,
+    pub bit_depth: Option<u64>,
+    pub chroma_subsampling: Option<String>,
+    pub color_primaries: Option<String>,
+    pub color_range: Option<String>,
+    pub color_space: Option<String>,
+    pub color_trc: Option<String>,
+    pub scan_type: Option<String>,
+    pub language: Option<String>,
+    pub language_code: Option<String>,
+    pub language_tag: Option<String>,
+    pub title: Option<String>,
+    pub display_title: Option<String>,
+    pub extended_display_title: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AudioStream {
+    pub id: u64,
+    pub stream_type: u64,
+    pub index: Option<u64>,
+    pub codec: String,
+    #[serde(default)]
+    pub default: bool,
+    #[serde(default)]
+    pub selected: bool,
+    pub channels: Option<u64>,
+    pub bitrate: Option<u64>,
+    pub sampling_rate: Option<u64>,
+    pub bit_depth: Option<u64>,
+    pub profile: Option<String>,
+    pub audio_channel_layout: Option<String>,
+    pub language: Option<String>,
+    pub language_code: Option<String>,
+    pub language_tag: Option<String>,
+    pub title: Option<String>,
+    pub display_title: Option<String>,
+    pub extended_display_title: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtitleStream {
+    pub id: u64,
+    pub stream_type: u64,
+    pub index: Option<u64>,
+    pub codec: Option<String>,
+    /// Only set for sidecar subtitles, e.g. `srt`
+    pub format: Option<String>,
+    /// Only set for sidecar subtitles, the path to download the file
+    pub key: Option<String>,
+    #[serde(default)]
+    pub default: bool,
+    #[serde(default)]
+    pub selected: bool,
+    #[serde(default)]
+    pub forced: bool,
+    #[serde(default)]
+    pub hearing_impaired: bool,
+    pub language: Option<String>,
+    pub language_code: Option<String>,
+    pub language_tag: Option<String>,
+    pub title: Option<String>,
+    pub display_title: Option<String>,
+    pub extended_display_title: Option<String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::Stream;
+
+    #[test]
+    fn test_stream_type_discriminates_variant() {
+        let json = r#"[
+            {"id":1,"streamType":1,"default":true,"codec":"h264","index":0,"bitrate":5000,"height":1080,"width":1920,"frameRate":23.976},
+            {"id":2,"streamType":2,"selected":true,"codec":"ac3","index":1,"channels":6,"language":"English","languageCode":"eng"},
+            {"id":3,"streamType":3,"codec":"srt","format":"srt","key":"/library/streams/3","forced":true,"languageCode":"ger"},
+            {"id":4,"streamType":4,"codec":"lrc"}
+        ]"#;
+
+        let streams: Vec<Stream> = serde_json::from_str(json).unwrap();
+
+        assert!(matches!(&streams[0], Stream::Video(stream) if stream.height == Some(1080)));
+        assert!(matches!(&streams[1], Stream::Audio(stream) if stream.selected && stream.channels == Some(6)));
+        assert!(matches!(&streams[2], Stream::Subtitle(stream) if stream.forced && !stream.selected));
+        assert!(matches!(&streams[3], Stream::Other(_)));
+        assert_eq!(streams[3].id(), Some(4));
+    }
+}
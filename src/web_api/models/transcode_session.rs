@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+// Only `key` is guaranteed, the end notification of a session carries nothing else.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TranscodeSession {
+    pub key: String,
+    pub throttled: Option<bool>,
+    pub complete: Option<bool>,
+    pub progress: Option<f64>,
+    pub size: Option<i64>,
+    pub speed: Option<f64>,
+    pub error: Option<bool>,
+    pub duration: Option<u64>,
+    pub remaining: Option<u64>,
+    /// `streaming` or `static`
+    pub context: Option<String>,
+    pub source_video_codec: Option<String>,
+    pub source_audio_codec: Option<String>,
+    /// `transcode`, `copy` or `directplay`
+    pub video_decision: Option<String>,
+    pub audio_decision: Option<String>,
+    pub subtitle_decision: Option<String>,
+    pub protocol: Option<String>,
+    pub container: Option<String>,
+    pub video_codec: Option<String>,
+    pub audio_codec: Option<String>,
+    pub audio_channels: Option<u64>,
+    pub width: Option<u64>,
+    pub height: Option<u64>,
+    pub transcode_hw_requested: Option<bool>,
+    pub transcode_hw_full_pipeline: Option<bool>,
+    pub max_offset_available: Option<f64>,
+    pub min_offset_available: Option<f64>,
+}
@@ -0,0 +1,114 @@
+use futures::{future, Stream, StreamExt};
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::error::TlsError;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tokio_tungstenite::Connector;
+
+use super::error::{self, Error};
+use super::models::{Notification, NotificationContainer};
+use super::PlexWebApi;
+
+impl PlexWebApi {
+    /// Connects to the notification websocket of the server.
+    ///
+    /// Requires `ServerInfo::event_stream`. The stream ends when the server closes the connection.
+    pub async fn notifications(&self) -> Result<impl Stream<Item = Result<Notification, Error>>, Error> {
+        let url = format!("{}/:/websockets/notifications", websocket_base_url(&self.base_url));
+        let mut request = url.as_str().into_client_request()?;
+        let mut headers = vec![("X-Plex-Token", self.auth_token.as_str())];
+        if let Some(identity) = &self.identity {
+            headers.append(&mut identity.parameters());
+        }
+        for (name, value) in headers {
+            let value = HeaderValue::from_str(value)
+                .map_err(|err| Error::from(tungstenite::Error::from(err)))?;
+            request.headers_mut().insert(name, value);
+        }
+        let connector = if self.accept_invalid_certs {
+            let connector = native_tls::TlsConnector::builder()
+                .danger_accept_invalid_certs(true)
+                .build()
+                .map_err(|err| Error::from(tungstenite::Error::from(TlsError::Native(err))))?;
+            Some(Connector::NativeTls(connector))
+        } else {
+            None
+        };
+        let (socket, _) = tokio_tungstenite::connect_async_tls_with_config(request, None, false, connector).await?;
+
+        let notifications = socket.filter_map(move |message| {
+            let notification = match message {
+                Ok(Message::Text(text)) => Some(error::decode::<NotificationContainer>(&url, text.as_bytes())
+                    .map(|container| container.notification)),
+                Ok(_) => None,
+                Err(err) => Some(Err(err.into())),
+            };
+            future::ready(notification)
+        });
+
+        Ok(notifications)
+    }
+}
+
+fn websocket_base_url(base_url: &str) -> String {
+    if let Some(url) = base_url.strip_prefix("https://") {
+        format!("wss://{}", url)
+    } else if let Some(url) = base_url.strip_prefix("http://") {
+        format!("ws://{}", url)
+    } else {
+        base_url.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{SinkExt, StreamExt};
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::handshake::server::{Request, Response};
+    use tokio_tungstenite::tungstenite::Message;
+
+    use super::websocket_base_url;
+    use crate::web_api::models::{Notification, PlaybackState};
+    use crate::web_api::PlexWebApi;
+
+    const PLAYING: &str = r#"{"NotificationContainer":{"type":"playing","size":1,"PlaySessionStateNotification":[{"sessionKey":"21","clientIdentifier":"abc","guid":"","ratingKey":"1337","url":"","key":"/library/metadata/1337","viewOffset":12000,"playQueueItemID":4,"state":"paused"}]}}"#;
+    const BACKGROUND: &str = r#"{"NotificationContainer":{"type":"backgroundProcessingQueue","size":1,"BackgroundProcessingQueueEventNotification":[{"queueID":1,"event":"queueRegenerated"}]}}"#;
+
+    #[test]
+    fn test_websocket_base_url() {
+        assert_eq!(websocket_base_url("http://127.0.0.1:32400"), "ws://127.0.0.1:32400");
+        assert_eq!(websocket_base_url("https://1-2-3-4.abc.plex.direct:32400"), "wss://1-2-3-4.abc.plex.direct:32400");
+    }
+
+    #[tokio::test]
+    #[allow(clippy::result_large_err)]
+    async fn test_notifications() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_hdr_async(stream, |request: &Request, response: Response| {
+                assert_eq!(request.uri().path(), "/:/websockets/notifications");
+                assert_eq!(request.headers()["X-Plex-Token"], "token");
+                Ok(response)
+            }).await.unwrap();
+            socket.send(Message::Text(PLAYING.into())).await.unwrap();
+            socket.send(Message::Text(BACKGROUND.into())).await.unwrap();
+            socket.close(None).await.unwrap();
+        });
+        let api = PlexWebApi::new(format!("http://{}", address), "token".to_string());
+
+        let notifications: Vec<_> = api.notifications().await.unwrap().collect().await;
+
+        server.await.unwrap();
+        assert_eq!(notifications.len(), 2);
+        match &notifications[0] {
+            Ok(Notification::Playing { sessions }) => {
+                assert_eq!(sessions[0].rating_key, "1337");
+                assert_eq!(sessions[0].state, PlaybackState::Paused);
+            }
+            notification => panic!("expected playing notification, got {:?}", notification)
+        }
+        assert!(matches!(notifications[1], Ok(Notification::Unknown)));
+    }
+}
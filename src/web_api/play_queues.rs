@@ -0,0 +1,122 @@
+use super::error::Error;
+use super::models::{MediaContainer, PlayQueue, PlaylistType};
+use super::{PlexWebApi, SectionQuery};
+
+/// Content a play queue is created from
+#[derive(Clone, Debug)]
+pub enum PlayQueueSource {
+    /// A single item, or all children of a show, season, artist or album
+    Item(String),
+    Playlist(String),
+    Section { section_id: String, query: SectionQuery },
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct PlayQueueOptions {
+    pub shuffle: bool,
+    pub repeat: bool,
+    /// Keep playing the following items of the source, e.g. the next episodes
+    pub continuous: bool,
+}
+
+impl PlexWebApi {
+    /// Creates a play queue of audio, video or photo items.
+    pub async fn create_play_queue(&self, source: &PlayQueueSource, queue_type: PlaylistType, options: &PlayQueueOptions) -> Result<PlayQueue, Error> {
+        let request = self.api_post(&format!("{}/playQueues", self.base_url))
+            .query(&[("type", queue_type)])
+            .query(&[
+                ("shuffle", options.shuffle as u8),
+                ("repeat", options.repeat as u8),
+                ("continuous", options.continuous as u8),
+            ])
+            .query(&self.play_queue_source_parameters(source).await?);
+        let res: MediaContainer<_> = self.fetch(request).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn play_queue(&self, play_queue_id: u64) -> Result<PlayQueue, Error> {
+        let url = format!("{}/playQueues/{}", self.base_url, play_queue_id);
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Adds the items to the end of the queue, or behind the current item if `next` is set.
+    pub async fn add_to_play_queue(&self, play_queue_id: u64, rating_keys: &[&str], next: bool) -> Result<PlayQueue, Error> {
+        let uri = self.items_uri(rating_keys).await?;
+        let request = self.api_put(&format!("{}/playQueues/{}", self.base_url, play_queue_id))
+            .query(&[("uri", uri)])
+            .query(&[("next", next as u8)]);
+        let res: MediaContainer<_> = self.fetch(request).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Moves an entry behind the entry `after`, `None` moves it to the top.
+    pub async fn move_play_queue_item(&self, play_queue_id: u64, play_queue_item_id: u64, after: Option<u64>) -> Result<PlayQueue, Error> {
+        let url = format!("{}/playQueues/{}/items/{}/move", self.base_url, play_queue_id, play_queue_item_id);
+        let mut request = self.api_put(&url);
+        if let Some(after) = after {
+            request = request.query(&[("after", after)]);
+        }
+        let res: MediaContainer<_> = self.fetch(request).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn remove_from_play_queue(&self, play_queue_id: u64, play_queue_item_id: u64) -> Result<PlayQueue, Error> {
+        let url = format!("{}/playQueues/{}/items/{}", self.base_url, play_queue_id, play_queue_item_id);
+        let res: MediaContainer<_> = self.fetch(self.api_delete(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    async fn play_queue_source_parameters(&self, source: &PlayQueueSource) -> Result<Vec<(&'static str, String)>, Error> {
+        let parameters = match source {
+            PlayQueueSource::Item(rating_key) => {
+                let key = format!("/library/metadata/{}", rating_key);
+                vec![("uri", self.library_uri(&key).await?), ("key", key)]
+            }
+            PlayQueueSource::Playlist(playlist_id) => vec![("playlistID", playlist_id.clone())],
+            PlayQueueSource::Section { section_id, query } => {
+                let path = format!("/library/sections/{}/all?{}", section_id, query.query_string());
+                vec![("uri", self.library_uri(&path).await?)]
+            }
+        };
+
+        Ok(parameters)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::PlayQueueSource;
+    use crate::web_api::models::MetadataType;
+    use crate::web_api::{PlexWebApi, SectionQuery};
+
+    #[tokio::test]
+    async fn test_source_parameters() {
+        let api = PlexWebApi::builder("http://127.0.0.1:9", "token")
+            .machine_identifier("abc")
+            .build()
+            .unwrap();
+        let section = PlayQueueSource::Section {
+            section_id: "2".into(),
+            query: SectionQuery::new().item_type(MetadataType::Movie).unwatched(),
+        };
+
+        let item = api.play_queue_source_parameters(&PlayQueueSource::Item("1".into())).await.unwrap();
+        let playlist = api.play_queue_source_parameters(&PlayQueueSource::Playlist("5".into())).await.unwrap();
+        let section = api.play_queue_source_parameters(&section).await.unwrap();
+
+        assert_eq!(item, vec![
+            ("uri", "server://abc/com.plexapp.plugins.library/library/metadata/1".to_string()),
+            ("key", "/library/metadata/1".to_string()),
+        ]);
+        assert_eq!(playlist, vec![("playlistID", "5".to_string())]);
+        assert_eq!(section, vec![
+            ("uri", "server://abc/com.plexapp.plugins.library/library/sections/2/all?type=1&unwatched=1".to_string()),
+        ]);
+    }
+}
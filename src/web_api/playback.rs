@@ -0,0 +1,146 @@
+use std::time::{Duration, Instant};
+
+use super::error::Error;
+use super::models::PlaybackState;
+use super::{PlexWebApi, LIBRARY_IDENTIFIER};
+
+const DEFAULT_TIMELINE_INTERVAL: Duration = Duration::from_secs(10);
+const MAX_RATING: u8 = 10;
+
+impl PlexWebApi {
+    /// Marks the item and all its children as watched.
+    pub async fn mark_watched<S: Into<String>>(&self, rating_key: S) -> Result<(), Error> {
+        let request = self.api_get(&format!("{}/:/scrobble", self.base_url))
+            .query(&[("key", rating_key.into().as_str()), ("identifier", LIBRARY_IDENTIFIER)]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Marks the item and all its children as unwatched.
+    pub async fn mark_unwatched<S: Into<String>>(&self, rating_key: S) -> Result<(), Error> {
+        let request = self.api_get(&format!("{}/:/unscrobble", self.base_url))
+            .query(&[("key", rating_key.into().as_str()), ("identifier", LIBRARY_IDENTIFIER)]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Sets the user rating from `0` to `10`, one point per half star. `None` removes the rating.
+    ///
+    /// Fails with [`Error::InvalidRating`] for ratings above `10`.
+    pub async fn rate<S: Into<String>>(&self, rating_key: S, rating: Option<u8>) -> Result<(), Error> {
+        let rating = match rating {
+            Some(rating) if rating > MAX_RATING => return Err(Error::InvalidRating { rating }),
+            Some(rating) => i16::from(rating),
+            None => -1,
+        };
+        let request = self.api_put(&format!("{}/:/rate", self.base_url))
+            .query(&[("key", rating_key.into().as_str()), ("identifier", LIBRARY_IDENTIFIER)])
+            .query(&[("rating", rating)]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Reports the playback progress of an item, this drives "Continue Watching" and the session list.
+    ///
+    /// The server associates the report with the client identity, so one should be configured.
+    pub async fn report_timeline<S: Into<String>>(&self, rating_key: S, state: PlaybackState, time_ms: u64, duration_ms: u64) -> Result<(), Error> {
+        let rating_key = rating_key.into();
+        let request = self.api_get(&format!("{}/:/timeline", self.base_url))
+            .query(&[("ratingKey", rating_key.clone()), ("key", format!("/library/metadata/{}", rating_key))])
+            .query(&[("state", state)])
+            .query(&[("time", time_ms), ("duration", duration_ms)]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Creates a reporter which throttles timeline updates of the item.
+    pub fn timeline_reporter<S: Into<String>>(&self, rating_key: S, duration_ms: u64) -> TimelineReporter {
+        TimelineReporter {
+            api: self.clone(),
+            rating_key: rating_key.into(),
+            duration_ms,
+            interval: DEFAULT_TIMELINE_INTERVAL,
+            last_report: None,
+        }
+    }
+}
+
+/// Throttles timeline reports while playing.
+///
+/// State changes are reported immediately, progress updates at most once per interval.
+/// Call [`TimelineReporter::stop`] when playback ends.
+#[derive(Debug)]
+pub struct TimelineReporter {
+    api: PlexWebApi,
+    rating_key: String,
+    duration_ms: u64,
+    interval: Duration,
+    last_report: Option<(PlaybackState, Instant)>,
+}
+
+impl TimelineReporter {
+    /// Minimum time between two reports of the same state, defaults to 10 seconds.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Reports the progress if required, returns whether a report has been sent.
+    pub async fn update(&mut self, state: PlaybackState, time_ms: u64) -> Result<bool, Error> {
+        let now = Instant::now();
+        if !self.should_report(state, now) {
+            return Ok(false);
+        }
+        self.api.report_timeline(self.rating_key.as_str(), state, time_ms, self.duration_ms).await?;
+        self.last_report = Some((state, now));
+
+        Ok(true)
+    }
+
+    /// Sends the final `stopped` report.
+    pub async fn stop(self, time_ms: u64) -> Result<(), Error> {
+        self.api.report_timeline(self.rating_key, PlaybackState::Stopped, time_ms, self.duration_ms).await
+    }
+
+    fn should_report(&self, state: PlaybackState, now: Instant) -> bool {
+        match self.last_report {
+            Some((last_state, reported_at)) => last_state != state || now.duration_since(reported_at) >= self.interval,
+            None => true
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::{Duration, Instant};
+
+    use crate::web_api::error::Error;
+    use crate::web_api::models::PlaybackState;
+    use crate::web_api::PlexWebApi;
+
+    #[test]
+    fn test_reporter_throttles_progress() {
+        let api = PlexWebApi::new("http://127.0.0.1:32400", "token");
+        let mut reporter = api.timeline_reporter("1337", 60000).interval(Duration::from_secs(10));
+        let start = Instant::now();
+
+        assert!(reporter.should_report(PlaybackState::Playing, start));
+        reporter.last_report = Some((PlaybackState::Playing, start));
+        assert!(!reporter.should_report(PlaybackState::Playing, start + Duration::from_secs(5)));
+        assert!(reporter.should_report(PlaybackState::Paused, start + Duration::from_secs(5)));
+        assert!(reporter.should_report(PlaybackState::Playing, start + Duration::from_secs(10)));
+    }
+
+    #[tokio::test]
+    async fn test_rate_rejects_out_of_range() {
+        let api = PlexWebApi::new("http://127.0.0.1:9", "token");
+
+        let res = api.rate("1337", Some(11)).await;
+
+        assert!(matches!(res, Err(Error::InvalidRating { rating: 11 })));
+    }
+}
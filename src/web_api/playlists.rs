@@ -0,0 +1,92 @@
+use super::error::Error;
+use super::models::{MediaContainer, Playlist, PlaylistItems, PlaylistType, Playlists};
+use super::{PlexWebApi, SectionQuery};
+
+impl PlexWebApi {
+    pub async fn playlists(&self) -> Result<Playlists, Error> {
+        let res: MediaContainer<_> = self.fetch(self.api_get(&format!("{}/playlists", self.base_url))).await?;
+
+        Ok(res.media_container)
+    }
+
+    pub async fn playlist<S: Into<String>>(&self, playlist_id: S) -> Result<Playlist, Error> {
+        let url = format!("{}/playlists/{}", self.base_url, playlist_id.into());
+        let res: MediaContainer<Playlists> = self.fetch(self.api_get(&url)).await?;
+
+        res.media_container.playlists
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound { url })
+    }
+
+    pub async fn playlist_items<S: Into<String>>(&self, playlist_id: S) -> Result<PlaylistItems, Error> {
+        let url = format!("{}/playlists/{}/items", self.base_url, playlist_id.into());
+        let res: MediaContainer<_> = self.fetch(self.api_get(&url)).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Creates a playlist containing the given items.
+    pub async fn create_playlist<T: Into<String>>(&self, title: T, playlist_type: PlaylistType, rating_keys: &[&str]) -> Result<Playlist, Error> {
+        let uri = self.items_uri(rating_keys).await?;
+
+        self.post_playlist(title.into(), playlist_type, false, uri).await
+    }
+
+    /// Creates a playlist which is kept up to date with the items of the section matching the query.
+    pub async fn create_smart_playlist<T: Into<String>, S: Into<String>>(&self, title: T, playlist_type: PlaylistType, section_id: S, query: &SectionQuery) -> Result<Playlist, Error> {
+        let path = format!("/library/sections/{}/all?{}", section_id.into(), query.query_string());
+        let uri = self.library_uri(&path).await?;
+
+        self.post_playlist(title.into(), playlist_type, true, uri).await
+    }
+
+    pub async fn delete_playlist<S: Into<String>>(&self, playlist_id: S) -> Result<(), Error> {
+        self.send(self.api_delete(&format!("{}/playlists/{}", self.base_url, playlist_id.into()))).await?;
+
+        Ok(())
+    }
+
+    /// Appends the items to the end of the playlist.
+    pub async fn add_to_playlist<S: Into<String>>(&self, playlist_id: S, rating_keys: &[&str]) -> Result<(), Error> {
+        let uri = self.items_uri(rating_keys).await?;
+        let request = self.api_put(&format!("{}/playlists/{}/items", self.base_url, playlist_id.into()))
+            .query(&[("uri", uri)]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    pub async fn remove_from_playlist<S: Into<String>>(&self, playlist_id: S, playlist_item_id: u64) -> Result<(), Error> {
+        let url = format!("{}/playlists/{}/items/{}", self.base_url, playlist_id.into(), playlist_item_id);
+        self.send(self.api_delete(&url)).await?;
+
+        Ok(())
+    }
+
+    /// Moves an entry behind the entry `after`, `None` moves it to the top.
+    pub async fn move_playlist_item<S: Into<String>>(&self, playlist_id: S, playlist_item_id: u64, after: Option<u64>) -> Result<(), Error> {
+        let url = format!("{}/playlists/{}/items/{}/move", self.base_url, playlist_id.into(), playlist_item_id);
+        let mut request = self.api_put(&url);
+        if let Some(after) = after {
+            request = request.query(&[("after", after)]);
+        }
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    async fn post_playlist(&self, title: String, playlist_type: PlaylistType, smart: bool, uri: String) -> Result<Playlist, Error> {
+        let url = format!("{}/playlists", self.base_url);
+        let request = self.api_post(&url)
+            .query(&[("type", playlist_type)])
+            .query(&[("title", title), ("uri", uri)])
+            .query(&[("smart", smart as u8)]);
+        let res: MediaContainer<Playlists> = self.fetch(request).await?;
+
+        res.media_container.playlists
+            .into_iter()
+            .next()
+            .ok_or(Error::NotFound { url })
+    }
+}
@@ -0,0 +1,205 @@
+use super::error::Error;
+use super::models::MetadataType;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterOperator {
+    Is,
+    IsNot,
+    GreaterThan,
+    LessThan,
+}
+
+impl FilterOperator {
+    /// Suffix of the field name, the `=` of the query string completes the operator
+    fn suffix(&self) -> &'static str {
+        match self {
+            FilterOperator::Is => "",
+            FilterOperator::IsNot => "!",
+            FilterOperator::GreaterThan => ">>",
+            FilterOperator::LessThan => "<<",
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter {
+    pub field: String,
+    pub operator: FilterOperator,
+    pub value: String,
+}
+
+/// Builds the query string to filter and sort the items of a library section.
+///
+/// ```
+/// use plex_rs::web_api::{SectionQuery, SortDirection};
+/// use plex_rs::web_api::models::MetadataType;
+///
+/// let query = SectionQuery::new()
+///     .item_type(MetadataType::Movie)
+///     .unwatched()
+///     .year_after(2000)
+///     .sort("addedAt", SortDirection::Descending);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SectionQuery {
+    item_type: Option<MetadataType>,
+    sort: Vec<(String, SortDirection)>,
+    filters: Vec<Filter>,
+}
+
+impl SectionQuery {
+    pub fn new() -> Self {
+        SectionQuery::default()
+    }
+
+    pub fn item_type(mut self, item_type: MetadataType) -> Self {
+        self.item_type = Some(item_type);
+        self
+    }
+
+    /// Can be called multiple times, later calls are used as tie breakers.
+    pub fn sort<S: Into<String>>(mut self, field: S, direction: SortDirection) -> Self {
+        self.sort.push((field.into(), direction));
+        self
+    }
+
+    pub fn filter<F: Into<String>, V: ToString>(mut self, field: F, operator: FilterOperator, value: V) -> Self {
+        self.filters.push(Filter {
+            field: field.into(),
+            operator,
+            value: value.to_string(),
+        });
+        self
+    }
+
+    pub fn unwatched(self) -> Self {
+        self.filter("unwatched", FilterOperator::Is, 1)
+    }
+
+    pub fn year_after(self, year: u64) -> Self {
+        self.filter("year", FilterOperator::GreaterThan, year)
+    }
+
+    pub fn year_before(self, year: u64) -> Self {
+        self.filter("year", FilterOperator::LessThan, year)
+    }
+
+    /// Filter by the key of a genre directory
+    pub fn genre<V: ToString>(self, genre: V) -> Self {
+        self.filter("genre", FilterOperator::Is, genre)
+    }
+
+    /// Filter by the key of an actor directory
+    pub fn actor<V: ToString>(self, actor: V) -> Self {
+        self.filter("actor", FilterOperator::Is, actor)
+    }
+
+    /// Filter by resolution, e.g. `4k`, `1080` or `sd`
+    pub fn resolution<V: ToString>(self, resolution: V) -> Self {
+        self.filter("resolution", FilterOperator::Is, resolution)
+    }
+
+    pub fn label<V: ToString>(self, label: V) -> Self {
+        self.filter("label", FilterOperator::Is, label)
+    }
+
+    pub fn filters(&self) -> &[Filter] {
+        &self.filters
+    }
+
+    pub fn query_parameters(&self) -> Vec<(String, String)> {
+        let mut parameters = Vec::new();
+        if let Some(item_type) = self.item_type {
+            parameters.push(("type".to_string(), item_type.id().to_string()));
+        }
+        if !self.sort.is_empty() {
+            let sort = self.sort.iter()
+                .map(|(field, direction)| match direction {
+                    SortDirection::Ascending => field.clone(),
+                    SortDirection::Descending => format!("{}:desc", field),
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            parameters.push(("sort".to_string(), sort));
+        }
+        for filter in &self.filters {
+            parameters.push((format!("{}{}", filter.field, filter.operator.suffix()), filter.value.clone()));
+        }
+
+        parameters
+    }
+
+    /// Url encoded query string, e.g. for the uri of a smart playlist.
+    pub fn query_string(&self) -> String {
+        url::form_urlencoded::Serializer::new(String::new())
+            .extend_pairs(self.query_parameters())
+            .finish()
+    }
+
+    /// Checks that every filter is advertised by the section.
+    ///
+    /// The advertised filters are listed by [`PlexWebApi::section_filters`](super::PlexWebApi::section_filters),
+    /// see [`SectionDefinitions::filter_names`](super::models::SectionDefinitions::filter_names).
+    pub fn validate<'a, I: IntoIterator<Item = &'a str>>(&self, advertised_filters: I) -> Result<(), Error> {
+        let advertised_filters: Vec<&str> = advertised_filters.into_iter().collect();
+        match self.filters.iter().find(|filter| !advertised_filters.contains(&filter.field.as_str())) {
+            Some(filter) => Err(Error::UnsupportedFilter { name: filter.field.clone() }),
+            None => Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FilterOperator, SectionQuery, SortDirection};
+    use crate::web_api::error::Error;
+    use crate::web_api::models::MetadataType;
+
+    #[test]
+    fn test_query_parameters() {
+        let query = SectionQuery::new()
+            .item_type(MetadataType::Movie)
+            .sort("addedAt", SortDirection::Descending)
+            .sort("titleSort", SortDirection::Ascending)
+            .unwatched()
+            .year_after(2000)
+            .filter("studio", FilterOperator::IsNot, "Pixar");
+
+        let parameters = query.query_parameters();
+
+        assert_eq!(parameters, vec![
+            ("type".to_string(), "1".to_string()),
+            ("sort".to_string(), "addedAt:desc,titleSort".to_string()),
+            ("unwatched".to_string(), "1".to_string()),
+            ("year>>".to_string(), "2000".to_string()),
+            ("studio!".to_string(), "Pixar".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn test_query_string() {
+        let query = SectionQuery::new()
+            .item_type(MetadataType::Track)
+            .year_after(2000)
+            .sort("addedAt", SortDirection::Descending);
+
+        assert_eq!(query.query_string(), "type=10&sort=addedAt%3Adesc&year%3E%3E=2000");
+    }
+
+    #[test]
+    fn test_validate_rejects_unknown_filter() {
+        let query = SectionQuery::new().genre(12).label(3);
+
+        assert!(query.validate(vec!["genre", "label"]).is_ok());
+        match query.validate(vec!["genre", "year"]) {
+            Err(Error::UnsupportedFilter { name }) => assert_eq!(name, "label"),
+            res => panic!("expected unsupported filter, got {:?}", res)
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use super::error::Error;
+use super::models::{History, MediaContainer, Sessions};
+use super::{HistoryQuery, PlexWebApi};
+
+impl PlexWebApi {
+    /// Items currently being played on the server.
+    pub async fn sessions(&self) -> Result<Sessions, Error> {
+        let res: MediaContainer<_> = self.fetch(self.api_get(&format!("{}/status/sessions", self.base_url))).await?;
+
+        Ok(res.media_container)
+    }
+
+    /// Stops the playback of a session, the reason is displayed to the user.
+    ///
+    /// The session id is the `Session::id` of a [`SessionEntry`](super::models::SessionEntry), not its session key.
+    pub async fn terminate_session<I: Into<String>, R: Into<String>>(&self, session_id: I, reason: R) -> Result<(), Error> {
+        let request = self.api_get(&format!("{}/status/sessions/terminate", self.base_url))
+            .query(&[("sessionId", session_id.into()), ("reason", reason.into())]);
+        self.send(request).await?;
+
+        Ok(())
+    }
+
+    /// Watch history of all users, filtered by the query.
+    pub async fn history(&self, query: &HistoryQuery) -> Result<History, Error> {
+        let mut request = self.api_get(&format!("{}/status/sessions/history/all", self.base_url))
+            .query(&query.query_parameters());
+        if let Some((start, size)) = query.paging() {
+            request = request
+                .header("X-Plex-Container-Start", start)
+                .header("X-Plex-Container-Size", size);
+        }
+        let res: MediaContainer<_> = self.fetch(request).await?;
+
+        Ok(res.media_container)
+    }
+}